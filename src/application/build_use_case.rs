@@ -1,20 +1,23 @@
 use crate::application::{build_context::BuildContext, gantt_use_case::GanttUseCase};
-use crate::domain::project_management::repository::ProjectRepository;
+use crate::domain::project_management::repository::{ProjectRepository, ProjectRepositoryWithId};
 use crate::domain::{
     company_management::repository::CompanyRepository, company_settings::repository::ConfigRepository,
     project_management::AnyProject,
 };
 use crate::infrastructure::persistence::{
-    config_repository::FileConfigRepository, project_repository::FileProjectRepository,
-    resource_repository::FileResourceRepository,
+    company_repository::FileCompanyRepository, config_repository::FileConfigRepository,
+    project_repository::FileProjectRepository, resource_repository::FileResourceRepository,
 };
 use crate::interface::assets::TemplateAssets;
 
 // glob no longer needed; using repository enumeration
 
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
 use tera::{Context, Tera};
 
 /// `BuildUseCase` is responsible for orchestrating the static site generation.
@@ -39,10 +42,22 @@ impl BuildUseCase {
             tera.add_raw_template(filename.as_ref(), content)?;
         }
 
+        // Resolved against `base_path` up front so `classify_change` can
+        // compare it against the absolute paths the watcher reports — a
+        // relative `output_dir` (e.g. the default "dist") would never match
+        // otherwise, and the build's own output would be misclassified as a
+        // source change.
+        let output_dir = PathBuf::from(output_dir);
+        let output_dir = if output_dir.is_absolute() {
+            output_dir
+        } else {
+            base_path.join(output_dir)
+        };
+
         Ok(Self {
             base_path,
             tera,
-            output_dir: PathBuf::from(output_dir),
+            output_dir,
             context,
         })
     }
@@ -78,6 +93,10 @@ impl BuildUseCase {
         // Load projects from repository (now handles both ID-based and hierarchical)
         let projects = project_repo.find_all().unwrap_or_default();
 
+        // Host-appropriate default for projects with no `spec.timezone`,
+        // resolved once per build the same way system tools do.
+        let system_timezone = detect_system_timezone();
+
         for project in projects {
             let company_code = project.company_code().to_string();
             let project_code = project.code().to_string();
@@ -98,12 +117,26 @@ impl BuildUseCase {
                 // Clone the project and update its timezone
                 let mut project_clone = project.clone();
                 let AnyProject::Project(ref mut p) = project_clone;
-                p.settings.timezone = Some(config.default_timezone.clone());
+                p.settings.timezone = Some(system_timezone.clone());
                 project_clone
             } else {
                 project
             };
 
+            // Catch typo'd timezones (e.g. "Europe/Londn") before any HTML
+            // is emitted, rather than letting them propagate silently.
+            if let Some(tz) = project.timezone() {
+                if tz.parse::<chrono_tz::Tz>().is_err() {
+                    return Err(format!(
+                        "Project '{}' has an invalid timezone '{}'. Did you mean '{}'?",
+                        project.code(),
+                        tz,
+                        closest_timezone_suggestion(tz)
+                    )
+                    .into());
+                }
+            }
+
             all_projects_data.push((project, tasks, resources, company_code));
         }
 
@@ -244,8 +277,11 @@ impl BuildUseCase {
                 tera::Value::Number(tera::Number::from(*resource_count)),
             );
 
-            // Create project summaries for company page
-            let project_summaries: Vec<_> = company_projects
+            // Create project summaries for company page, in the same order
+            // the company's own Gantt context uses, so the index/detail
+            // pages and the Gantt page agree.
+            let ordered_company_projects = self.order_company_projects(company_projects);
+            let project_summaries: Vec<_> = ordered_company_projects
                 .iter()
                 .map(|(project, tasks, _, _)| {
                     let mut project_map = tera::Map::new();
@@ -260,6 +296,9 @@ impl BuildUseCase {
                         ),
                     );
                     project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
+                    if let Some(tz) = project.timezone() {
+                        project_map.insert("timezone_label".to_string(), tera::Value::String(format_timezone_label(tz)));
+                    }
                     project_map.insert(
                         "task_count".to_string(),
                         tera::Value::Number(tera::Number::from(tasks.len())),
@@ -371,6 +410,10 @@ impl BuildUseCase {
                             project_map.insert("code".to_string(), tera::Value::String(project.code().to_string()));
                             project_map.insert("name".to_string(), tera::Value::String(project.name().to_string()));
                             project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
+                            if let Some(tz) = project.timezone() {
+                                project_map
+                                    .insert("timezone_label".to_string(), tera::Value::String(format_timezone_label(tz)));
+                            }
                             project_map.insert(
                                 "task_count".to_string(),
                                 tera::Value::Number(tera::Number::from(project_tasks.len())),
@@ -450,144 +493,518 @@ impl BuildUseCase {
             fs::create_dir_all(&projects_base_dir)?;
 
             for (project, tasks, resources, _) in company_projects {
-                let project_code = project.code();
-                let _project_name = project.name();
-
-                let project_output_dir = projects_base_dir.join(project_code);
+                let project_output_dir = projects_base_dir.join(project.code());
                 fs::create_dir_all(&project_output_dir)?;
+                self.render_project_pages(&company_map, company_code, &project_output_dir, project, tasks, resources, None)?;
+            }
+        }
 
-                let mut project_context = Context::new();
-                // Create a simplified project object for the template
-                let mut project_map = tera::Map::new();
-                project_map.insert("code".to_string(), tera::Value::String(project.code().to_string()));
-                project_map.insert("name".to_string(), tera::Value::String(project.name().to_string()));
-                project_map.insert(
-                    "description".to_string(),
-                    tera::Value::String(
-                        project
-                            .description()
-                            .map_or("No description available.".to_string(), |d| d.to_string()),
-                    ),
-                );
-                project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
-                project_map.insert(
-                    "start_date".to_string(),
-                    project
-                        .start_date()
-                        .map_or(tera::Value::Null, |d| tera::Value::String(d.to_string())),
-                );
-                project_map.insert(
-                    "end_date".to_string(),
-                    project
-                        .end_date()
-                        .map_or(tera::Value::Null, |d| tera::Value::String(d.to_string())),
-                );
+        // 11. Generate the cross-project tag taxonomy pages.
+        self.render_tag_pages(&all_projects_data)?;
 
-                project_context.insert("project", &tera::Value::Object(project_map.clone()));
-                project_context.insert("company", &tera::Value::Object(company_map.clone()));
-                project_context.insert("tasks", tasks);
-                project_context.insert("resources", resources);
-                project_context.insert("relative_path_prefix", "../../../");
-                project_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
-                
-                // Add Gantt chart variables
-                project_context.insert("gantt_available", &true);
-                project_context.insert("company_gantt_url", &"../gantt.html");
-                project_context.insert("project_gantt_url", &"gantt.html");
-                project_context.insert("all_projects_gantt_url", &"../../gantt.html");
-                
-                // Add current page variable
-                project_context.insert("current_page", &"projects");
+        // 12. Export a TaskWarrior-compatible tasks.json per project, plus
+        // an aggregate at the site root.
+        self.export_taskwarrior_json(&all_projects_data)?;
 
-                // Render project detail page (e.g., project.html)
-                let project_html = match self.tera.render("project.html", &project_context) {
-                    Ok(html) => html,
-                    Err(e) => {
-                        return Err(format!("Template error: {}", e).into());
-                    }
-                };
-                let project_page_path = project_output_dir.join("index.html");
-                fs::write(project_page_path, project_html)?;
+        // 13. Generate the site-wide timezone reference page.
+        self.render_timezones_page(&all_projects_data, &system_timezone)?;
 
+        Ok(())
+    }
 
-                // Generate project detail page
-                let project_detail_html = match self.tera.render("project_detail.html", &project_context) {
-                    Ok(html) => html,
-                    Err(e) => {
-                        return Err(format!("Template error: {}", e).into());
-                    }
-                };
-                let project_detail_path = project_output_dir.join("detail.html");
-                fs::write(project_detail_path, project_detail_html)?;
-
-                // Gerar página Gantt do projeto (project_gantt.html)
-                let project_gantt_page_path = project_output_dir.join("gantt.html");
-                let project_gantt_context =
-                    self.create_project_gantt_context(project, tasks, resources, &company_map)?;
-                let project_gantt_html = match self.tera.render("project_gantt.html", &project_gantt_context) {
-                    Ok(html) => html,
-                    Err(e) => {
-                        println!("Project Gantt template error: {:?}", e);
-                        return Err(format!("Template error: {}", e).into());
-                    }
-                };
-                fs::write(project_gantt_page_path, project_gantt_html)?;
-
-                // Generate task detail pages
-                let tasks_base_dir = project_output_dir.join("tasks");
-                fs::create_dir_all(&tasks_base_dir)?;
-
-                for task in tasks {
-                    let task_code = task.code();
-                    let task_output_dir = tasks_base_dir.join(task_code);
-                    fs::create_dir_all(&task_output_dir)?;
-
-                    let mut task_context = Context::new();
-                    task_context.insert("task", &task);
-                    task_context.insert("project", &tera::Value::Object(project_map.clone()));
-                    task_context.insert("company", &tera::Value::Object(company_map.clone()));
-                    task_context.insert("relative_path_prefix", "../../../../");
-                    task_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
-                    
-                    // Add Gantt chart variables
-                    task_context.insert("gantt_available", &true);
-                    task_context.insert("company_gantt_url", &"../../gantt.html");
-                    task_context.insert("project_gantt_url", &"../gantt.html");
-                    task_context.insert("all_projects_gantt_url", &"../../../gantt.html");
-                    
-                    // Add current page variable
-                    task_context.insert("current_page", &"tasks");
-
-                    // Create dummy project for base template (used only for the base template)
-                    let dummy_project: AnyProject = crate::domain::project_management::builder::ProjectBuilder::new()
-                        .code("TASK_DASHBOARD".to_string())
-                        .name(format!("{} Task Dashboard", task.name()))
-                        .company_code(company_code.to_string())
-                        .created_by("system".to_string())
-                        .build()
-                        .unwrap()
-                        .into();
-                    // Override the project in context with the actual project data for task templates
-                    task_context.insert("project", &tera::Value::Object(project_map.clone()));
-                    // Keep dummy project for base template compatibility
-                    task_context.insert("base_project", &dummy_project);
-
-                    // Generate task detail page
-                    let task_detail_html = match self.tera.render("task_detail.html", &task_context) {
-                        Ok(html) => html,
-                        Err(e) => {
-                            return Err(format!("Template error: {}", e).into());
-                        }
-                    };
-                    let task_detail_path = task_output_dir.join("detail.html");
-                    fs::write(task_detail_path, task_detail_html)?;
+    /// Renders a single project's own pages — `project.html`, `detail.html`,
+    /// `gantt.html`, and each task's `tasks/<code>/detail.html` — against
+    /// `company_map` (the same fields `execute` already builds for this
+    /// project's company). Pass `only_task` to re-render a single task page
+    /// and skip the project-level pages, for [`Self::rebuild_project_pages`]'s
+    /// partial rebuilds; `execute` passes `None` to render everything.
+    #[allow(clippy::too_many_arguments)]
+    fn render_project_pages(
+        &self,
+        company_map: &tera::Map<String, tera::Value>,
+        company_code: &str,
+        project_output_dir: &Path,
+        project: &AnyProject,
+        tasks: &[crate::domain::task_management::AnyTask],
+        resources: &[crate::domain::resource_management::AnyResource],
+        only_task: Option<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut project_map = tera::Map::new();
+        project_map.insert("code".to_string(), tera::Value::String(project.code().to_string()));
+        project_map.insert("name".to_string(), tera::Value::String(project.name().to_string()));
+        project_map.insert(
+            "description".to_string(),
+            tera::Value::String(
+                project
+                    .description()
+                    .map_or("No description available.".to_string(), |d| d.to_string()),
+            ),
+        );
+        project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
+        if let Some(tz) = project.timezone() {
+            project_map.insert("timezone_label".to_string(), tera::Value::String(format_timezone_label(tz)));
+        }
+        project_map.insert(
+            "start_date".to_string(),
+            project
+                .start_date()
+                .map_or(tera::Value::Null, |d| tera::Value::String(d.to_string())),
+        );
+        project_map.insert(
+            "end_date".to_string(),
+            project
+                .end_date()
+                .map_or(tera::Value::Null, |d| tera::Value::String(d.to_string())),
+        );
+
+        if only_task.is_none() {
+            // Same order the project's own Gantt context uses, so the
+            // index/detail pages and the Gantt page agree.
+            let ordered_tasks = self.order_tasks(tasks);
+
+            let mut project_context = Context::new();
+            project_context.insert("project", &tera::Value::Object(project_map.clone()));
+            project_context.insert("company", &tera::Value::Object(company_map.clone()));
+            project_context.insert("tasks", &ordered_tasks);
+            project_context.insert("resources", resources);
+            project_context.insert("relative_path_prefix", "../../../");
+            project_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+            project_context.insert("gantt_available", &true);
+            project_context.insert("company_gantt_url", &"../gantt.html");
+            project_context.insert("project_gantt_url", &"gantt.html");
+            project_context.insert("all_projects_gantt_url", &"../../gantt.html");
+            project_context.insert("current_page", &"projects");
+
+            let project_html = match self.tera.render("project.html", &project_context) {
+                Ok(html) => html,
+                Err(e) => return Err(format!("Template error: {}", e).into()),
+            };
+            fs::write(project_output_dir.join("index.html"), project_html)?;
+
+            let project_detail_html = match self.tera.render("project_detail.html", &project_context) {
+                Ok(html) => html,
+                Err(e) => return Err(format!("Template error: {}", e).into()),
+            };
+            fs::write(project_output_dir.join("detail.html"), project_detail_html)?;
+
+            let project_gantt_context = self.create_project_gantt_context(project, tasks, resources, company_map)?;
+            let project_gantt_html = match self.tera.render("project_gantt.html", &project_gantt_context) {
+                Ok(html) => html,
+                Err(e) => {
+                    println!("Project Gantt template error: {:?}", e);
+                    return Err(format!("Template error: {}", e).into());
                 }
+            };
+            fs::write(project_output_dir.join("gantt.html"), project_gantt_html)?;
+        }
+
+        let tasks_base_dir = project_output_dir.join("tasks");
+        fs::create_dir_all(&tasks_base_dir)?;
+
+        for task in tasks {
+            if only_task.is_some_and(|code| code != task.code()) {
+                continue;
             }
+
+            let task_output_dir = tasks_base_dir.join(task.code());
+            fs::create_dir_all(&task_output_dir)?;
+
+            let mut task_context = Context::new();
+            task_context.insert("task", &task);
+            task_context.insert("urgency", &compute_task_urgency(task, tasks, chrono::Utc::now().date_naive()));
+            task_context.insert("project", &tera::Value::Object(project_map.clone()));
+            task_context.insert("company", &tera::Value::Object(company_map.clone()));
+            task_context.insert("relative_path_prefix", "../../../../");
+            task_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+            task_context.insert("gantt_available", &true);
+            task_context.insert("company_gantt_url", &"../../gantt.html");
+            task_context.insert("project_gantt_url", &"../gantt.html");
+            task_context.insert("all_projects_gantt_url", &"../../../gantt.html");
+            task_context.insert("current_page", &"tasks");
+
+            // Dummy project used only by the base template's header.
+            let dummy_project: AnyProject = crate::domain::project_management::builder::ProjectBuilder::new()
+                .code("TASK_DASHBOARD".to_string())
+                .name(format!("{} Task Dashboard", task.name()))
+                .company_code(company_code.to_string())
+                .created_by("system".to_string())
+                .build()
+                .unwrap()
+                .into();
+            task_context.insert("base_project", &dummy_project);
+
+            let task_detail_html = match self.tera.render("task_detail.html", &task_context) {
+                Ok(html) => html,
+                Err(e) => return Err(format!("Template error: {}", e).into()),
+            };
+            fs::write(task_output_dir.join("detail.html"), task_detail_html)?;
         }
 
         Ok(())
     }
 
+    /// Builds the `tags/` taxonomy: one `index.html` listing every distinct
+    /// tag with its member count, and one `tags/<slug>/index.html` per tag
+    /// listing its (company, project, task) members.
+    ///
+    /// The only field in this domain model actually named `tags` is the task
+    /// manifest's on-disk `tags:` key, which loads into
+    /// `AnyTask::assigned_resources` (see `task_manifest.rs`) — `Project`
+    /// itself carries no tags field — so that's the source indexed here.
+    fn render_tag_pages(
+        &self,
+        all_projects_data: &[(
+            AnyProject,
+            Vec<crate::domain::task_management::AnyTask>,
+            Vec<crate::domain::resource_management::AnyResource>,
+            String,
+        )],
+    ) -> Result<(), Box<dyn Error>> {
+        struct TagEntry {
+            company_code: String,
+            project_code: String,
+            task_code: String,
+            task_name: String,
+        }
+
+        let mut by_tag: HashMap<String, Vec<TagEntry>> = HashMap::new();
+        for (project, tasks, _, company_code) in all_projects_data {
+            for task in tasks {
+                for tag in task.assigned_resources() {
+                    by_tag.entry(tag.clone()).or_default().push(TagEntry {
+                        company_code: company_code.clone(),
+                        project_code: project.code().to_string(),
+                        task_code: task.code().to_string(),
+                        task_name: task.name().to_string(),
+                    });
+                }
+            }
+        }
+
+        let tags_dir = self.output_dir.join("tags");
+        fs::create_dir_all(&tags_dir)?;
+
+        let mut tag_names: Vec<&String> = by_tag.keys().collect();
+        tag_names.sort();
+
+        let dummy_project: AnyProject = crate::domain::project_management::builder::ProjectBuilder::new()
+            .code("TAGS_DASHBOARD".to_string())
+            .name("Tags".to_string())
+            .company_code("TTR".to_string())
+            .created_by("system".to_string())
+            .build()
+            .unwrap()
+            .into();
+
+        let tag_summaries: Vec<_> = tag_names
+            .iter()
+            .map(|tag| {
+                let mut map = tera::Map::new();
+                map.insert("name".to_string(), tera::Value::String((**tag).clone()));
+                map.insert("slug".to_string(), tera::Value::String(slugify(tag)));
+                map.insert(
+                    "count".to_string(),
+                    tera::Value::Number(tera::Number::from(by_tag[tag.as_str()].len())),
+                );
+                tera::Value::Object(map)
+            })
+            .collect();
+
+        let mut index_context = Context::new();
+        index_context.insert("tags", &tag_summaries);
+        index_context.insert("relative_path_prefix", "../");
+        index_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+        index_context.insert("current_page", &"tags");
+        index_context.insert("project", &dummy_project);
+
+        let tags_index_html = match self.tera.render("tags_index.html", &index_context) {
+            Ok(html) => html,
+            Err(e) => return Err(format!("Template error: {}", e).into()),
+        };
+        fs::write(tags_dir.join("index.html"), tags_index_html)?;
+
+        for tag in &tag_names {
+            let slug = slugify(tag);
+            let tag_output_dir = tags_dir.join(&slug);
+            fs::create_dir_all(&tag_output_dir)?;
+
+            let members: Vec<_> = by_tag[tag.as_str()]
+                .iter()
+                .map(|entry| {
+                    let mut map = tera::Map::new();
+                    map.insert("company_code".to_string(), tera::Value::String(entry.company_code.clone()));
+                    map.insert("project_code".to_string(), tera::Value::String(entry.project_code.clone()));
+                    map.insert("task_code".to_string(), tera::Value::String(entry.task_code.clone()));
+                    map.insert("task_name".to_string(), tera::Value::String(entry.task_name.clone()));
+                    map.insert(
+                        "detail_url".to_string(),
+                        tera::Value::String(format!(
+                            "../../companies/{}/projects/{}/tasks/{}/detail.html",
+                            entry.company_code, entry.project_code, entry.task_code
+                        )),
+                    );
+                    tera::Value::Object(map)
+                })
+                .collect();
+
+            let mut tag_context = Context::new();
+            tag_context.insert("tag_name", tag.as_str());
+            tag_context.insert("members", &members);
+            tag_context.insert("relative_path_prefix", "../../");
+            tag_context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+            tag_context.insert("current_page", &"tags");
+            tag_context.insert("project", &dummy_project);
+
+            let tag_html = match self.tera.render("tag_detail.html", &tag_context) {
+                Ok(html) => html,
+                Err(e) => return Err(format!("Template error: {}", e).into()),
+            };
+            fs::write(tag_output_dir.join("index.html"), tag_html)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders `timezones/index.html` at the site root: every distinct
+    /// timezone referenced by the loaded projects, plus the detected system
+    /// default, each with its friendly label, current UTC offset, and the
+    /// projects that use it.
+    fn render_timezones_page(
+        &self,
+        all_projects_data: &[(
+            AnyProject,
+            Vec<crate::domain::task_management::AnyTask>,
+            Vec<crate::domain::resource_management::AnyResource>,
+            String,
+        )],
+        system_timezone: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        struct ProjectRef {
+            company_code: String,
+            project_code: String,
+            project_name: String,
+        }
+
+        let mut by_zone: HashMap<String, Vec<ProjectRef>> = HashMap::new();
+        by_zone.entry(system_timezone.to_string()).or_default();
+        for (project, _, _, company_code) in all_projects_data {
+            let zone = project.timezone().cloned().unwrap_or_else(|| system_timezone.to_string());
+            by_zone.entry(zone).or_default().push(ProjectRef {
+                company_code: company_code.clone(),
+                project_code: project.code().to_string(),
+                project_name: project.name().to_string(),
+            });
+        }
+
+        let mut zone_names: Vec<&String> = by_zone.keys().collect();
+        zone_names.sort();
+
+        use chrono::{Offset, TimeZone};
+
+        let now_utc = chrono::Utc::now().naive_utc();
+        let zones: Vec<_> = zone_names
+            .iter()
+            .map(|zone| {
+                let mut map = tera::Map::new();
+                map.insert("id".to_string(), tera::Value::String((**zone).clone()));
+                map.insert("label".to_string(), tera::Value::String(format_timezone_label(zone)));
+
+                let offset = zone
+                    .parse::<chrono_tz::Tz>()
+                    .map(|tz| tz.offset_from_utc_datetime(&now_utc).fix().to_string())
+                    .unwrap_or_else(|_| "+00:00".to_string());
+                map.insert("utc_offset".to_string(), tera::Value::String(offset));
+
+                map.insert(
+                    "is_system_default".to_string(),
+                    tera::Value::Bool(zone.as_str() == system_timezone),
+                );
+
+                let projects: Vec<_> = by_zone[zone.as_str()]
+                    .iter()
+                    .map(|p| {
+                        let mut pmap = tera::Map::new();
+                        pmap.insert("company_code".to_string(), tera::Value::String(p.company_code.clone()));
+                        pmap.insert("project_code".to_string(), tera::Value::String(p.project_code.clone()));
+                        pmap.insert("project_name".to_string(), tera::Value::String(p.project_name.clone()));
+                        pmap.insert(
+                            "detail_url".to_string(),
+                            tera::Value::String(format!(
+                                "../companies/{}/projects/{}/detail.html",
+                                p.company_code, p.project_code
+                            )),
+                        );
+                        tera::Value::Object(pmap)
+                    })
+                    .collect();
+                map.insert("projects".to_string(), tera::Value::Array(projects));
+
+                tera::Value::Object(map)
+            })
+            .collect();
+
+        let timezones_dir = self.output_dir.join("timezones");
+        fs::create_dir_all(&timezones_dir)?;
+
+        let dummy_project: AnyProject = crate::domain::project_management::builder::ProjectBuilder::new()
+            .code("TIMEZONES_DASHBOARD".to_string())
+            .name("Timezones".to_string())
+            .company_code("TTR".to_string())
+            .created_by("system".to_string())
+            .build()
+            .unwrap()
+            .into();
+
+        let mut context = Context::new();
+        context.insert("timezones", &zones);
+        context.insert("relative_path_prefix", "../");
+        context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+        context.insert("current_page", &"timezones");
+        context.insert("project", &dummy_project);
+
+        let timezones_html = match self.tera.render("timezones_index.html", &context) {
+            Ok(html) => html,
+            Err(e) => return Err(format!("Template error: {}", e).into()),
+        };
+        fs::write(timezones_dir.join("index.html"), timezones_html)?;
+
+        Ok(())
+    }
+
+    /// Writes a TaskWarrior-compatible `tasks.json` per project, alongside
+    /// its HTML pages, plus an aggregate at the site root — so a plan built
+    /// here can be piped straight into `task import`.
+    fn export_taskwarrior_json(
+        &self,
+        all_projects_data: &[(
+            AnyProject,
+            Vec<crate::domain::task_management::AnyTask>,
+            Vec<crate::domain::resource_management::AnyResource>,
+            String,
+        )],
+    ) -> Result<(), Box<dyn Error>> {
+        let mut all_entries = Vec::new();
+
+        for (project, tasks, _, company_code) in all_projects_data {
+            // Dependencies are stored as task codes; TaskWarrior's `depends`
+            // wants uuids, so resolve within this project's own task list.
+            let uuid_by_code: HashMap<&str, String> =
+                tasks.iter().map(|t| (t.code(), t.id().to_string())).collect();
+
+            let project_entries: Vec<TaskWarriorTask> = tasks
+                .iter()
+                .map(|task| TaskWarriorTask::from_task(task, project.code(), &uuid_by_code))
+                .collect();
+
+            let project_output_dir = self
+                .output_dir
+                .join("companies")
+                .join(company_code)
+                .join("projects")
+                .join(project.code());
+            fs::create_dir_all(&project_output_dir)?;
+            let project_json = serde_json::to_string_pretty(&project_entries)?;
+            fs::write(project_output_dir.join("tasks.json"), project_json)?;
+
+            all_entries.extend(project_entries);
+        }
+
+        let aggregate_json = serde_json::to_string_pretty(&all_entries)?;
+        fs::write(self.output_dir.join("tasks.json"), aggregate_json)?;
+
+        Ok(())
+    }
+
+    /// Reads `config.yaml`'s `sort_by` (`date`, `name`, `status`, `priority`,
+    /// or `none`), defaulting to `none` when unset or the config can't be
+    /// loaded, so callers don't have to thread the config through.
+    fn resolved_sort_order(&self) -> String {
+        let config_repo = FileConfigRepository::with_base_path(self.base_path.clone());
+        config_repo
+            .load()
+            .ok()
+            .and_then(|(config, _)| config.sort_by)
+            .unwrap_or_else(|| "none".to_string())
+    }
+
+    /// Orders a company's projects per `resolved_sort_order`. Shared by the
+    /// company index/detail page and the company Gantt context so both
+    /// agree on the same order.
+    #[allow(clippy::type_complexity)]
+    fn order_company_projects<'a>(
+        &self,
+        company_projects: &[&'a (
+            crate::domain::project_management::AnyProject,
+            Vec<crate::domain::task_management::AnyTask>,
+            Vec<crate::domain::resource_management::AnyResource>,
+            String,
+        )],
+    ) -> Vec<
+        &'a (
+            crate::domain::project_management::AnyProject,
+            Vec<crate::domain::task_management::AnyTask>,
+            Vec<crate::domain::resource_management::AnyResource>,
+            String,
+        ),
+    > {
+        let sort_by = self.resolved_sort_order();
+        let mut ordered: Vec<_> = company_projects.to_vec();
+        match sort_by.as_str() {
+            "date" => ordered.sort_by(|(a, ..), (b, ..)| match (a.start_date(), b.start_date()) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }),
+            "name" => ordered.sort_by(|(a, ..), (b, ..)| a.name().cmp(b.name())),
+            "status" => ordered.sort_by(|(a, ..), (b, ..)| a.status().to_string().cmp(&b.status().to_string())),
+            _ => {}
+        }
+        ordered
+    }
+
+    /// Orders a project's tasks per `resolved_sort_order`, with urgency as
+    /// the tie-breaker (or the order itself when `sort_by` is `none`).
+    /// Shared by the project index/detail page and the project Gantt
+    /// context so both agree on the same order.
+    fn order_tasks<'a>(
+        &self,
+        tasks: &'a [crate::domain::task_management::AnyTask],
+    ) -> Vec<&'a crate::domain::task_management::AnyTask> {
+        let today = chrono::Utc::now().date_naive();
+        let urgency_of = |task: &crate::domain::task_management::AnyTask| compute_task_urgency(task, tasks, today);
+
+        let sort_by = self.resolved_sort_order();
+        let mut ordered_tasks: Vec<&crate::domain::task_management::AnyTask> = tasks.iter().collect();
+        match sort_by.as_str() {
+            "date" => ordered_tasks.sort_by(|a, b| {
+                a.start_date()
+                    .cmp(b.start_date())
+                    .then_with(|| urgency_of(b).partial_cmp(&urgency_of(a)).unwrap_or(std::cmp::Ordering::Equal))
+            }),
+            "name" => ordered_tasks.sort_by(|a, b| {
+                a.name()
+                    .cmp(b.name())
+                    .then_with(|| urgency_of(b).partial_cmp(&urgency_of(a)).unwrap_or(std::cmp::Ordering::Equal))
+            }),
+            "status" => ordered_tasks.sort_by(|a, b| {
+                a.status()
+                    .to_string()
+                    .cmp(&b.status().to_string())
+                    .then_with(|| urgency_of(b).partial_cmp(&urgency_of(a)).unwrap_or(std::cmp::Ordering::Equal))
+            }),
+            "priority" => ordered_tasks.sort_by(|a, b| {
+                b.priority()
+                    .value()
+                    .cmp(&a.priority().value())
+                    .then_with(|| urgency_of(b).partial_cmp(&urgency_of(a)).unwrap_or(std::cmp::Ordering::Equal))
+            }),
+            _ => ordered_tasks
+                .sort_by(|a, b| urgency_of(b).partial_cmp(&urgency_of(a)).unwrap_or(std::cmp::Ordering::Equal)),
+        }
+        ordered_tasks
+    }
+
     /// Cria o contexto para o template company_gantt.html
     fn create_company_gantt_context(
         &self,
@@ -625,8 +1042,12 @@ impl BuildUseCase {
             tera::Value::Number(tera::Number::from(company_resources.len())),
         );
 
+        // Order the projects according to config.yaml's `sort_by` before
+        // rendering them, so the index, detail, and Gantt pages all agree.
+        let ordered_projects = self.order_company_projects(company_projects);
+
         // Projects data for Gantt
-        let projects: Vec<_> = company_projects
+        let projects: Vec<_> = ordered_projects
             .iter()
             .map(|(project, _, _, _)| {
                 let mut project_map = tera::Map::new();
@@ -641,6 +1062,9 @@ impl BuildUseCase {
                     ),
                 );
                 project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
+                if let Some(tz) = project.timezone() {
+                    project_map.insert("timezone_label".to_string(), tera::Value::String(format_timezone_label(tz)));
+                }
                 project_map.insert(
                     "task_count".to_string(),
                     tera::Value::Number(tera::Number::from(0)), // Will be calculated from tasks
@@ -665,18 +1089,24 @@ impl BuildUseCase {
             })
             .collect();
 
-        // Calculate company date range
+        // Calculate company date range. Each project's start/end date is
+        // resolved to a DST-safe instant in that project's own timezone
+        // before comparing, rather than comparing naive dates directly.
         let company_start_date = company_projects
             .iter()
-            .filter_map(|(project, _, _, _)| project.start_date())
+            .filter_map(|(project, _, _, _)| {
+                project.start_date().map(|d| resolve_midnight_in_timezone(d, project_timezone(project)))
+            })
             .min()
-            .map(|d| d.to_string())
+            .map(|dt| dt.date_naive().to_string())
             .unwrap_or_else(|| "2024-01-01".to_string());
         let company_end_date = company_projects
             .iter()
-            .filter_map(|(project, _, _, _)| project.end_date())
+            .filter_map(|(project, _, _, _)| {
+                project.end_date().map(|d| resolve_midnight_in_timezone(d, project_timezone(project)))
+            })
             .max()
-            .map(|d| d.to_string())
+            .map(|dt| dt.date_naive().to_string())
             .unwrap_or_else(|| "2024-12-31".to_string());
 
         context.insert("company", &tera::Value::Object(company_map));
@@ -734,6 +1164,9 @@ impl BuildUseCase {
             ),
         );
         project_map.insert("status".to_string(), tera::Value::String(project.status().to_string()));
+        if let Some(tz) = project.timezone() {
+            project_map.insert("timezone_label".to_string(), tera::Value::String(format_timezone_label(tz)));
+        }
         project_map.insert(
             "start_date".to_string(),
             project
@@ -765,9 +1198,48 @@ impl BuildUseCase {
             resource_maps.push(tera::Value::Object(resource_map));
         }
 
+        // Run the real Critical Path Method over the tasks' dependency graph
+        // instead of hardcoding every date, so the Gantt chart and its
+        // critical-path highlight reflect the project's actual schedule.
+        use crate::application::project::compute_critical_path::compute_critical_path;
+        use crate::application::scheduling::resolve::TaskNode;
+
+        let nodes: Vec<TaskNode> = tasks
+            .iter()
+            .map(|task| TaskNode {
+                code: task.code().to_string(),
+                dependencies: task.dependencies().to_vec(),
+                start_date: *task.start_date(),
+                due_date: *task.due_date(),
+            })
+            .collect();
+
+        let report = compute_critical_path(&nodes).map_err(|e| format!("Could not schedule tasks: {}", e))?;
+        let timings_by_code: HashMap<&str, &crate::application::project::compute_critical_path::TaskTiming> =
+            report.timings.iter().map(|t| (t.code.as_str(), t)).collect();
+
+        // CPM's earliest/latest start/finish are day offsets from the
+        // project's own start, not calendar dates — anchor them on the
+        // earliest start_date among the project's tasks.
+        let project_anchor_date = nodes
+            .iter()
+            .map(|n| n.start_date)
+            .min()
+            .or_else(|| project.start_date())
+            .unwrap_or_else(|| chrono::Utc::now().date_naive());
+        // Resolved through the project's timezone for a DST-safe instant,
+        // then taken back to a date for the day-offset arithmetic below.
+        let project_anchor = resolve_midnight_in_timezone(project_anchor_date, project_timezone(project)).date_naive();
+
+        // Order the tasks according to config.yaml's `sort_by` before
+        // rendering them, so the index, detail, and Gantt pages all agree.
+        // Urgency breaks ties within an explicit sort, and is the order
+        // itself when `sort_by` is `none` — it's the "what's next" ranking.
+        let ordered_tasks = self.order_tasks(tasks);
+
         // Convert tasks to a format that Tera can handle
         let mut task_maps = Vec::new();
-        for task in tasks {
+        for task in ordered_tasks {
             let mut task_map = tera::Map::new();
             task_map.insert("id".to_string(), tera::Value::String(task.id().to_string()));
             task_map.insert("code".to_string(), tera::Value::String(task.code().to_string()));
@@ -780,18 +1252,41 @@ impl BuildUseCase {
                         .map_or("No description available.".to_string(), |d| d.to_string()),
                 ),
             );
+
+            let timing = timings_by_code.get(task.code());
+            let start_date = timing.map(|t| project_anchor + chrono::Duration::days(t.earliest_start));
+            let end_date = timing.map(|t| project_anchor + chrono::Duration::days(t.earliest_finish));
+            let is_critical = timing.is_some_and(|t| t.slack == 0);
+            let is_milestone = timing.is_some_and(|t| t.earliest_finish == t.earliest_start);
+
             task_map.insert(
                 "start_date".to_string(),
-                tera::Value::String("2024-01-01".to_string()),
+                tera::Value::String(start_date.map_or_else(|| task.start_date().to_string(), |d| d.to_string())),
             );
             task_map.insert(
                 "end_date".to_string(),
-                tera::Value::String("2024-12-31".to_string()),
+                tera::Value::String(end_date.map_or_else(|| task.due_date().to_string(), |d| d.to_string())),
             );
             task_map.insert("progress".to_string(), tera::Value::Number(0.into()));
             task_map.insert("assigned_resources".to_string(), tera::Value::Array(vec![]));
-            task_map.insert("dependencies".to_string(), tera::Value::Array(vec![]));
-            task_map.insert("is_milestone".to_string(), tera::Value::Bool(false));
+            task_map.insert(
+                "urgency".to_string(),
+                tera::Number::from_f64(compute_task_urgency(task, tasks, chrono::Utc::now().date_naive()))
+                    .map(tera::Value::Number)
+                    .unwrap_or(tera::Value::Null),
+            );
+            task_map.insert(
+                "dependencies".to_string(),
+                tera::Value::Array(
+                    task.dependencies()
+                        .iter()
+                        .map(|code| tera::Value::String(code.clone()))
+                        .collect(),
+                ),
+            );
+            task_map.insert("slack".to_string(), tera::Value::Number(timing.map_or(0, |t| t.slack).into()));
+            task_map.insert("is_critical".to_string(), tera::Value::Bool(is_critical));
+            task_map.insert("is_milestone".to_string(), tera::Value::Bool(is_milestone));
             task_maps.push(tera::Value::Object(task_map));
         }
 
@@ -824,6 +1319,1025 @@ impl BuildUseCase {
 
         Ok(context)
     }
+
+    /// Watches the source tree (`config.yaml`, `companies/`, `projects/`,
+    /// the latter's `tasks/`, and `resources/`) and regenerates only the
+    /// output pages a change affects, instead of re-running [`Self::execute`]
+    /// in full. Bursts of events (e.g. an editor's save-as-temp-then-rename)
+    /// are coalesced with a 300ms debounce before each batch is classified
+    /// and rebuilt. Runs a full [`Self::execute`] up front and again whenever
+    /// `config.yaml` itself changes, since too much of every page derives
+    /// from it to track precisely, or a resource file outside any project's
+    /// `resources/` changes.
+    pub fn watch(&self) -> Result<(), Box<dyn Error>> {
+        use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+        println!("Building site once before watching for changes...");
+        self.execute()?;
+
+        let (tx, rx) = mpsc::channel::<notify::Event>();
+        let mut watcher = RecommendedWatcher::new(
+            move |res: Result<notify::Event, notify::Error>| {
+                if let Ok(event) = res {
+                    let _ = tx.send(event);
+                }
+            },
+            notify::Config::default(),
+        )?;
+        watcher.watch(&self.base_path, RecursiveMode::Recursive)?;
+
+        println!("Watching {} for changes...", self.base_path.display());
+
+        const DEBOUNCE: Duration = Duration::from_millis(300);
+        loop {
+            let Ok(first) = rx.recv() else {
+                break;
+            };
+            let mut paths: Vec<PathBuf> = first.paths;
+            while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+                paths.extend(event.paths);
+            }
+
+            let mut targets: Vec<WatchTarget> = paths.iter().map(|p| self.classify_change(p)).collect();
+            targets.dedup();
+
+            if targets.iter().any(|t| *t == WatchTarget::FullRebuild) {
+                println!("config.yaml or a workspace resource changed, rebuilding the whole site...");
+                self.execute()?;
+                continue;
+            }
+
+            for target in targets {
+                match target {
+                    WatchTarget::FullRebuild | WatchTarget::Ignored => {}
+                    WatchTarget::Project { project_ident, task_code } => {
+                        println!("Rebuilding project '{}'...", project_ident);
+                        if let Err(e) = self.rebuild_project_pages(&project_ident, task_code.as_deref()) {
+                            println!("Failed to rebuild project '{}': {}", project_ident, e);
+                        }
+                    }
+                    WatchTarget::Company(company_code) => {
+                        println!("Rebuilding company '{}'...", company_code);
+                        if let Err(e) = self.rebuild_company_gantt_and_index(&company_code) {
+                            println!("Failed to rebuild company '{}': {}", company_code, e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Maps a changed source path to the output pages it affects, per the
+    /// on-disk layout `<base_path>/config.yaml`, `companies/<code>.yaml`,
+    /// `projects/<id>.yaml`, and `projects/<id>/tasks/<code>.yaml` (plus the
+    /// legacy hierarchical `companies/<code>/projects/<code>/...` layout,
+    /// which nests the same `projects`/`tasks` segments).
+    fn classify_change(&self, path: &Path) -> WatchTarget {
+        if path.starts_with(&self.output_dir) {
+            return WatchTarget::Ignored;
+        }
+
+        if path.file_name().and_then(|f| f.to_str()) == Some("config.yaml") {
+            return WatchTarget::FullRebuild;
+        }
+
+        let components: Vec<&str> = path
+            .strip_prefix(&self.base_path)
+            .unwrap_or(path)
+            .components()
+            .filter_map(|c| c.as_os_str().to_str())
+            .collect();
+
+        if let Some(pos) = components.iter().position(|&c| c == "projects") {
+            if let Some(&ident) = components.get(pos + 1) {
+                let project_ident = ident.trim_end_matches(".yaml").to_string();
+                let task_code = components
+                    .iter()
+                    .position(|&c| c == "tasks")
+                    .filter(|&tasks_pos| tasks_pos > pos)
+                    .and_then(|tasks_pos| components.get(tasks_pos + 1))
+                    .map(|code| code.trim_end_matches(".yaml").to_string());
+                return WatchTarget::Project { project_ident, task_code };
+            }
+        }
+
+        if let Some(pos) = components.iter().position(|&c| c == "companies") {
+            if let Some(&ident) = components.get(pos + 1) {
+                return WatchTarget::Company(ident.trim_end_matches(".yaml").to_string());
+            }
+        }
+
+        // A bare top-level `resources/<id>.yaml` (the flat store from
+        // `FileResourceRepository::get_resources_path`, as opposed to the
+        // per-project `.../projects/<id>/resources/...` layout already
+        // handled by the `projects` branch above) isn't scoped to a single
+        // company or project in its path, so fall back to a full rebuild
+        // rather than silently ignoring it.
+        if components.iter().any(|&c| c == "resources") {
+            return WatchTarget::FullRebuild;
+        }
+
+        WatchTarget::Ignored
+    }
+
+    /// Re-renders one project's `project.html`, `project_detail.html`,
+    /// `project_gantt.html`, and (when `task_code` is known) just that one
+    /// task's `detail.html`, without touching any other project or company
+    /// page. `project_ident` is tried as a code first, then as the
+    /// repository's internal id, since `classify_change` can't tell which
+    /// one a bare file stem is.
+    fn rebuild_project_pages(&self, project_ident: &str, task_code: Option<&str>) -> Result<(), Box<dyn Error>> {
+        let project_repository = FileProjectRepository::with_base_path(self.base_path.clone());
+        let project = match project_repository.find_by_code(project_ident)? {
+            Some(project) => project,
+            None => match project_repository.find_by_id(project_ident)? {
+                Some(project) => project,
+                None => return Ok(()),
+            },
+        };
+
+        let company_code = project.company_code().to_string();
+        let project_code = project.code().to_string();
+
+        let company_repo = FileCompanyRepository::new(self.base_path.clone());
+        let Some(company) = company_repo.find_all()?.into_iter().find(|c| c.code() == company_code) else {
+            return Ok(());
+        };
+
+        let resource_repo = FileResourceRepository::new(self.base_path.clone());
+        let resources = resource_repo.find_all_by_project(&company_code, &project_code)?;
+
+        let mut tasks: Vec<_> = project.tasks().values().cloned().collect();
+        {
+            use std::collections::HashSet;
+            let mut seen: HashSet<String> = HashSet::new();
+            tasks.retain(|t| seen.insert(t.code().to_string()));
+        }
+
+        // Mirrors the same two fields execute() puts on every company_map:
+        // project_count and resource_count summed across this company's own
+        // projects (not just this one).
+        let sibling_projects = project_repository.find_all().unwrap_or_default();
+        let project_count = sibling_projects.iter().filter(|p| p.company_code() == company_code).count();
+        let resource_count: usize = sibling_projects
+            .iter()
+            .filter(|p| p.company_code() == company_code)
+            .map(|p| resource_repo.find_all_by_project(&company_code, p.code()).unwrap_or_default().len())
+            .sum();
+
+        let mut company_map = tera::Map::new();
+        company_map.insert("code".to_string(), tera::Value::String(company.code().to_string()));
+        company_map.insert("name".to_string(), tera::Value::String(company.name().to_string()));
+        company_map.insert(
+            "description".to_string(),
+            tera::Value::String(company.description.as_deref().unwrap_or("No description available.").to_string()),
+        );
+        company_map.insert("project_count".to_string(), tera::Value::Number(tera::Number::from(project_count)));
+        company_map.insert("resource_count".to_string(), tera::Value::Number(tera::Number::from(resource_count)));
+
+        let project_output_dir = self.output_dir.join("companies").join(&company_code).join("projects").join(&project_code);
+        fs::create_dir_all(&project_output_dir)?;
+
+        self.render_project_pages(&company_map, &company_code, &project_output_dir, &project, &tasks, &resources, task_code)
+    }
+
+    /// Re-renders one company's `gantt.html` plus the global dashboard — the
+    /// two pages the request scopes a company-level change to — without
+    /// touching that company's own `index.html`/`detail.html`/resource pages
+    /// or any project page.
+    fn rebuild_company_gantt_and_index(&self, company_code: &str) -> Result<(), Box<dyn Error>> {
+        let company_repo = FileCompanyRepository::new(self.base_path.clone());
+        let companies = company_repo.find_all()?;
+        let Some(company) = companies.iter().find(|c| c.code() == company_code) else {
+            return Ok(());
+        };
+
+        let project_repository = FileProjectRepository::with_base_path(self.base_path.clone());
+        let resource_repo = FileResourceRepository::new(self.base_path.clone());
+        let projects = project_repository.find_all().unwrap_or_default();
+
+        let mut company_projects_data = Vec::new();
+        for project in projects.into_iter().filter(|p| p.company_code() == company_code) {
+            let project_code = project.code().to_string();
+            let resources = resource_repo.find_all_by_project(company_code, &project_code).unwrap_or_default();
+            let mut tasks: Vec<_> = project.tasks().values().cloned().collect();
+            {
+                use std::collections::HashSet;
+                let mut seen: HashSet<String> = HashSet::new();
+                tasks.retain(|t| seen.insert(t.code().to_string()));
+            }
+            company_projects_data.push((project, tasks, resources, company_code.to_string()));
+        }
+        let company_projects_refs: Vec<&_> = company_projects_data.iter().collect();
+
+        let company_resources = resource_repo.find_all_by_project(company_code, "").unwrap_or_default();
+
+        let company_output_dir = self.output_dir.join("companies").join(company_code);
+        fs::create_dir_all(&company_output_dir)?;
+        let company_gantt_context = self.create_company_gantt_context(company, &company_projects_refs, &company_resources)?;
+        let company_gantt_html = match self.tera.render("company_gantt.html", &company_gantt_context) {
+            Ok(html) => html,
+            Err(e) => return Err(format!("Template error: {}", e).into()),
+        };
+        fs::write(company_output_dir.join("gantt.html"), company_gantt_html)?;
+
+        self.render_global_index()
+    }
+
+    /// Rebuilds the global dashboard (`index.html`/`companies.html`) from
+    /// the companies' and projects' current on-disk state, without touching
+    /// any per-company or per-project page.
+    fn render_global_index(&self) -> Result<(), Box<dyn Error>> {
+        let config_repo = FileConfigRepository::with_base_path(self.base_path.clone());
+        let (config, _) = config_repo.load()?;
+
+        let mut manager_map = tera::Map::new();
+        manager_map.insert("name".to_string(), tera::Value::String(config.manager_name.clone()));
+        manager_map.insert("email".to_string(), tera::Value::String(config.manager_email.clone()));
+
+        let company_repo = FileCompanyRepository::new(self.base_path.clone());
+        let companies = company_repo.find_all()?;
+
+        let project_repository = FileProjectRepository::with_base_path(self.base_path.clone());
+        let resource_repo = FileResourceRepository::new(self.base_path.clone());
+        let projects = project_repository.find_all().unwrap_or_default();
+
+        let company_values: Vec<_> = companies
+            .iter()
+            .map(|company| {
+                let company_code = company.code();
+                let project_count = projects.iter().filter(|p| p.company_code() == company_code).count();
+                let resource_count: usize = projects
+                    .iter()
+                    .filter(|p| p.company_code() == company_code)
+                    .map(|p| resource_repo.find_all_by_project(company_code, p.code()).unwrap_or_default().len())
+                    .sum();
+
+                let mut company_map = tera::Map::new();
+                company_map.insert("code".to_string(), tera::Value::String(company.code().to_string()));
+                company_map.insert("name".to_string(), tera::Value::String(company.name().to_string()));
+                company_map.insert(
+                    "description".to_string(),
+                    tera::Value::String(
+                        company.description.as_deref().unwrap_or("No description available.").to_string(),
+                    ),
+                );
+                company_map.insert("project_count".to_string(), tera::Value::Number(tera::Number::from(project_count)));
+                company_map.insert("resource_count".to_string(), tera::Value::Number(tera::Number::from(resource_count)));
+                tera::Value::Object(company_map)
+            })
+            .collect();
+
+        let total_projects = projects.len();
+        let total_resources: usize = projects
+            .iter()
+            .map(|p| resource_repo.find_all_by_project(p.company_code(), p.code()).unwrap_or_default().len())
+            .sum();
+
+        let mut context = Context::new();
+        context.insert("companies", &company_values);
+        context.insert("total_projects", &total_projects);
+        context.insert("total_resources", &total_resources);
+        context.insert("manager", &tera::Value::Object(manager_map));
+        context.insert("company_name", &config.company_name);
+        context.insert("relative_path_prefix", "/");
+        context.insert("current_date", &chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string());
+        context.insert("gantt_available", &true);
+        context.insert("company_gantt_url", &"companies/gantt.html");
+        context.insert("project_gantt_url", &"projects/gantt.html");
+        context.insert("all_projects_gantt_url", &"gantt.html");
+        context.insert("current_page", &"dashboard");
+
+        let dummy_project: AnyProject = crate::domain::project_management::builder::ProjectBuilder::new()
+            .code("TTR_DASHBOARD".to_string())
+            .name("TaskTaskRevolution Dashboard".to_string())
+            .company_code("TTR".to_string())
+            .created_by("system".to_string())
+            .end_date(chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .build()
+            .unwrap()
+            .into();
+        context.insert("project", &dummy_project);
+
+        let index_html = match self.tera.render("index.html", &context) {
+            Ok(html) => html,
+            Err(e) => return Err(format!("Template error: {}", e).into()),
+        };
+        fs::write(self.output_dir.join("index.html"), index_html.clone())?;
+        fs::write(self.output_dir.join("companies.html"), index_html)?;
+
+        Ok(())
+    }
+}
+
+/// What a changed source path means for [`BuildUseCase::watch`]: which
+/// output pages need re-rendering, or whether the whole site does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WatchTarget {
+    /// `config.yaml` — every page derives something from it — or a resource
+    /// file that isn't scoped to a single project.
+    FullRebuild,
+    /// A company file, or something under `companies/<code>/` that isn't a
+    /// project path.
+    Company(String),
+    /// A project file, or one of its tasks; `task_code` is set when the
+    /// change can be pinned to a single task file.
+    Project { project_ident: String, task_code: Option<String> },
+    /// Outside the watched tree, or inside the generated output itself.
+    Ignored,
+}
+
+/// One task in TaskWarrior's import/export JSON shape (see `task export`).
+#[derive(Debug, Clone, serde::Serialize)]
+struct TaskWarriorTask {
+    uuid: String,
+    description: String,
+    status: String,
+    entry: String,
+    due: String,
+    priority: String,
+    project: String,
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    depends: String,
+}
+
+impl TaskWarriorTask {
+    fn from_task(
+        task: &crate::domain::task_management::AnyTask,
+        project_code: &str,
+        uuid_by_code: &HashMap<&str, String>,
+    ) -> Self {
+        // TaskWarrior's own status vocabulary: pending/completed/deleted
+        // (waiting/recurring don't have an equivalent in this domain model).
+        let status = match task.status() {
+            "Completed" => "completed",
+            "Cancelled" => "deleted",
+            _ => "pending",
+        };
+
+        // TaskWarrior's native priorities are H/M/L; Critical maps onto H,
+        // since there's no higher tier in its vocabulary.
+        let priority = match task.priority() {
+            crate::domain::task_management::priority::Priority::Critical
+            | crate::domain::task_management::priority::Priority::High => "H",
+            crate::domain::task_management::priority::Priority::Medium => "M",
+            crate::domain::task_management::priority::Priority::Low => "L",
+        };
+
+        let depends = task
+            .dependencies()
+            .iter()
+            .filter_map(|code| uuid_by_code.get(code.as_str()))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Self {
+            uuid: task.id().to_string(),
+            description: task.name().to_string(),
+            status: status.to_string(),
+            entry: format!("{}T000000Z", task.start_date().format("%Y%m%d")),
+            due: format!("{}T000000Z", task.due_date().format("%Y%m%d")),
+            priority: priority.to_string(),
+            project: project_code.to_string(),
+            tags: task.assigned_resources().to_vec(),
+            depends,
+        }
+    }
+}
+
+/// TaskWarrior-style urgency: a weighted sum of a priority term, a due-date
+/// term that ramps up as the due date approaches/passes, an age term for
+/// how long the task has been open, a small per-tag term, a flat term when
+/// other tasks depend on this one, and a term for being actively worked.
+/// Higher means "work on this sooner".
+fn compute_task_urgency(
+    task: &crate::domain::task_management::AnyTask,
+    all_tasks: &[crate::domain::task_management::AnyTask],
+    today: chrono::NaiveDate,
+) -> f64 {
+    let priority_term = match task.priority() {
+        crate::domain::task_management::priority::Priority::Critical
+        | crate::domain::task_management::priority::Priority::High => 6.0,
+        crate::domain::task_management::priority::Priority::Medium => 3.9,
+        crate::domain::task_management::priority::Priority::Low => 1.8,
+    };
+
+    // Ramps from 0.2 (due 14+ days out) up to a cap of 12.0 (due today or
+    // already overdue).
+    let days_until_due = (*task.due_date() - today).num_days() as f64;
+    let due_term = if days_until_due <= 0.0 {
+        12.0
+    } else if days_until_due >= 14.0 {
+        0.2
+    } else {
+        0.2 + (14.0 - days_until_due) / 14.0 * (12.0 - 0.2)
+    };
+
+    // No creation timestamp exists on a task; start_date is the closest
+    // stand-in for "entry" date.
+    let age_days = (today - *task.start_date()).num_days().max(0) as f64;
+    let age_term = (age_days / 365.0).min(1.0) * 2.0;
+
+    let tag_term = task.assigned_resources().len() as f64 * 1.0;
+
+    let blocking_term = if all_tasks.iter().any(|t| t.dependencies().contains(&task.code().to_string())) {
+        8.0
+    } else {
+        0.0
+    };
+
+    let active_term = if task.status() == "InProgress" { 4.0 } else { 0.0 };
+
+    priority_term + due_term + age_term + tag_term + blocking_term + active_term
+}
+
+/// Resolves a calendar date's midnight in a project's timezone to a stable,
+/// documented instant, handling DST transitions: an ambiguous local time
+/// (fall-back) deterministically picks the earlier offset, and a
+/// nonexistent one (spring-forward gap) advances to the next valid instant
+/// instead of erroring.
+fn resolve_midnight_in_timezone(date: chrono::NaiveDate, tz: chrono_tz::Tz) -> chrono::DateTime<chrono_tz::Tz> {
+    let naive_midnight = date.and_hms_opt(0, 0, 0).expect("midnight is always a valid NaiveTime");
+    match naive_midnight.and_local_timezone(tz) {
+        chrono::LocalResult::Single(dt) => dt,
+        chrono::LocalResult::Ambiguous(earlier, _later) => earlier,
+        chrono::LocalResult::None => {
+            let mut candidate = naive_midnight;
+            loop {
+                candidate += chrono::Duration::minutes(1);
+                if let chrono::LocalResult::Single(dt) = candidate.and_local_timezone(tz) {
+                    break dt;
+                }
+            }
+        }
+    }
+}
+
+/// Parses a project's (already build-time-validated) `timezone` into a
+/// `chrono_tz::Tz`, falling back to UTC when it's unset.
+fn project_timezone(project: &AnyProject) -> chrono_tz::Tz {
+    project.timezone().and_then(|tz| tz.parse().ok()).unwrap_or(chrono_tz::UTC)
+}
+
+/// Renders an IANA timezone identifier as a human-friendly label, e.g.
+/// `"London, United Kingdom (Europe/London)"`. The single formatter used
+/// by every project page and listing so they all agree on the wording.
+fn format_timezone_label(iana_id: &str) -> String {
+    let locality = iana_id
+        .rsplit('/')
+        .next()
+        .unwrap_or(iana_id)
+        .replace('_', " ");
+    let region = timezone_country(iana_id).unwrap_or_else(|| {
+        iana_id.split('/').next().unwrap_or(iana_id).to_string()
+    });
+    format!("{}, {} ({})", locality, region, iana_id)
+}
+
+/// Maps an IANA zone to its primary country/region name, covering every
+/// canonical zone in `chrono_tz::TZ_VARIANTS` (the tzdata `zone1970.tab`
+/// country assignments), not just a handful of well-known ones. Unlisted
+/// zones (deprecated aliases, `Etc/*`) fall back to their leading path
+/// component in `format_timezone_label`.
+fn timezone_country(iana_id: &str) -> Option<String> {
+    TZ_COUNTRIES
+        .iter()
+        .find(|(id, _)| *id == iana_id)
+        .map(|(_, country)| country.to_string())
+}
+
+/// IANA zone identifier -> primary country/region, per tzdata's
+/// `zone1970.tab`. `UTC`/`GMT` and the fixed-offset `EST`/`PST`/`CST`/`MST`
+/// compatibility names are treated as US-anchored or zone-less, matching
+/// how `Config::is_valid_timezone` already lists them.
+const TZ_COUNTRIES: &[(&str, &str)] = &[
+    ("UTC", "Coordinated Universal Time"),
+    ("GMT", "Coordinated Universal Time"),
+    ("EST", "United States"),
+    ("PST", "United States"),
+    ("CST", "United States"),
+    ("MST", "United States"),
+    // Africa
+    ("Africa/Abidjan", "Côte d'Ivoire"),
+    ("Africa/Accra", "Ghana"),
+    ("Africa/Addis_Ababa", "Ethiopia"),
+    ("Africa/Algiers", "Algeria"),
+    ("Africa/Asmara", "Eritrea"),
+    ("Africa/Bamako", "Mali"),
+    ("Africa/Bangui", "Central African Republic"),
+    ("Africa/Banjul", "Gambia"),
+    ("Africa/Bissau", "Guinea-Bissau"),
+    ("Africa/Blantyre", "Malawi"),
+    ("Africa/Brazzaville", "Congo-Brazzaville"),
+    ("Africa/Bujumbura", "Burundi"),
+    ("Africa/Cairo", "Egypt"),
+    ("Africa/Casablanca", "Morocco"),
+    ("Africa/Ceuta", "Spain"),
+    ("Africa/Conakry", "Guinea"),
+    ("Africa/Dakar", "Senegal"),
+    ("Africa/Dar_es_Salaam", "Tanzania"),
+    ("Africa/Djibouti", "Djibouti"),
+    ("Africa/Douala", "Cameroon"),
+    ("Africa/El_Aaiun", "Western Sahara"),
+    ("Africa/Freetown", "Sierra Leone"),
+    ("Africa/Gaborone", "Botswana"),
+    ("Africa/Harare", "Zimbabwe"),
+    ("Africa/Johannesburg", "South Africa"),
+    ("Africa/Juba", "South Sudan"),
+    ("Africa/Kampala", "Uganda"),
+    ("Africa/Khartoum", "Sudan"),
+    ("Africa/Kigali", "Rwanda"),
+    ("Africa/Kinshasa", "Congo-Kinshasa"),
+    ("Africa/Lagos", "Nigeria"),
+    ("Africa/Libreville", "Gabon"),
+    ("Africa/Lome", "Togo"),
+    ("Africa/Luanda", "Angola"),
+    ("Africa/Lubumbashi", "Congo-Kinshasa"),
+    ("Africa/Lusaka", "Zambia"),
+    ("Africa/Malabo", "Equatorial Guinea"),
+    ("Africa/Maputo", "Mozambique"),
+    ("Africa/Maseru", "Lesotho"),
+    ("Africa/Mbabane", "Eswatini"),
+    ("Africa/Mogadishu", "Somalia"),
+    ("Africa/Monrovia", "Liberia"),
+    ("Africa/Nairobi", "Kenya"),
+    ("Africa/Ndjamena", "Chad"),
+    ("Africa/Niamey", "Niger"),
+    ("Africa/Nouakchott", "Mauritania"),
+    ("Africa/Ouagadougou", "Burkina Faso"),
+    ("Africa/Porto-Novo", "Benin"),
+    ("Africa/Sao_Tome", "São Tomé and Príncipe"),
+    ("Africa/Tripoli", "Libya"),
+    ("Africa/Tunis", "Tunisia"),
+    ("Africa/Windhoek", "Namibia"),
+    // America
+    ("America/Adak", "United States"),
+    ("America/Anchorage", "United States"),
+    ("America/Anguilla", "Anguilla"),
+    ("America/Antigua", "Antigua and Barbuda"),
+    ("America/Araguaina", "Brazil"),
+    ("America/Argentina/Buenos_Aires", "Argentina"),
+    ("America/Argentina/Catamarca", "Argentina"),
+    ("America/Argentina/Cordoba", "Argentina"),
+    ("America/Argentina/Jujuy", "Argentina"),
+    ("America/Argentina/La_Rioja", "Argentina"),
+    ("America/Argentina/Mendoza", "Argentina"),
+    ("America/Argentina/Rio_Gallegos", "Argentina"),
+    ("America/Argentina/Salta", "Argentina"),
+    ("America/Argentina/San_Juan", "Argentina"),
+    ("America/Argentina/San_Luis", "Argentina"),
+    ("America/Argentina/Tucuman", "Argentina"),
+    ("America/Argentina/Ushuaia", "Argentina"),
+    ("America/Aruba", "Aruba"),
+    ("America/Asuncion", "Paraguay"),
+    ("America/Atikokan", "Canada"),
+    ("America/Bahia", "Brazil"),
+    ("America/Bahia_Banderas", "Mexico"),
+    ("America/Barbados", "Barbados"),
+    ("America/Belem", "Brazil"),
+    ("America/Belize", "Belize"),
+    ("America/Blanc-Sablon", "Canada"),
+    ("America/Boa_Vista", "Brazil"),
+    ("America/Bogota", "Colombia"),
+    ("America/Boise", "United States"),
+    ("America/Cambridge_Bay", "Canada"),
+    ("America/Campo_Grande", "Brazil"),
+    ("America/Cancun", "Mexico"),
+    ("America/Caracas", "Venezuela"),
+    ("America/Cayenne", "French Guiana"),
+    ("America/Cayman", "Cayman Islands"),
+    ("America/Chicago", "United States"),
+    ("America/Chihuahua", "Mexico"),
+    ("America/Ciudad_Juarez", "Mexico"),
+    ("America/Costa_Rica", "Costa Rica"),
+    ("America/Creston", "Canada"),
+    ("America/Cuiaba", "Brazil"),
+    ("America/Curacao", "Curaçao"),
+    ("America/Danmarkshavn", "Greenland"),
+    ("America/Dawson", "Canada"),
+    ("America/Dawson_Creek", "Canada"),
+    ("America/Denver", "United States"),
+    ("America/Detroit", "United States"),
+    ("America/Dominica", "Dominica"),
+    ("America/Edmonton", "Canada"),
+    ("America/Eirunepe", "Brazil"),
+    ("America/El_Salvador", "El Salvador"),
+    ("America/Fort_Nelson", "Canada"),
+    ("America/Fortaleza", "Brazil"),
+    ("America/Glace_Bay", "Canada"),
+    ("America/Goose_Bay", "Canada"),
+    ("America/Grand_Turk", "Turks and Caicos Islands"),
+    ("America/Grenada", "Grenada"),
+    ("America/Guadeloupe", "Guadeloupe"),
+    ("America/Guatemala", "Guatemala"),
+    ("America/Guayaquil", "Ecuador"),
+    ("America/Guyana", "Guyana"),
+    ("America/Halifax", "Canada"),
+    ("America/Havana", "Cuba"),
+    ("America/Hermosillo", "Mexico"),
+    ("America/Indiana/Indianapolis", "United States"),
+    ("America/Indiana/Knox", "United States"),
+    ("America/Indiana/Marengo", "United States"),
+    ("America/Indiana/Petersburg", "United States"),
+    ("America/Indiana/Tell_City", "United States"),
+    ("America/Indiana/Vevay", "United States"),
+    ("America/Indiana/Vincennes", "United States"),
+    ("America/Indiana/Winamac", "United States"),
+    ("America/Inuvik", "Canada"),
+    ("America/Iqaluit", "Canada"),
+    ("America/Jamaica", "Jamaica"),
+    ("America/Juneau", "United States"),
+    ("America/Kentucky/Louisville", "United States"),
+    ("America/Kentucky/Monticello", "United States"),
+    ("America/Kralendijk", "Caribbean Netherlands"),
+    ("America/La_Paz", "Bolivia"),
+    ("America/Lima", "Peru"),
+    ("America/Los_Angeles", "United States"),
+    ("America/Lower_Princes", "Sint Maarten"),
+    ("America/Maceio", "Brazil"),
+    ("America/Managua", "Nicaragua"),
+    ("America/Manaus", "Brazil"),
+    ("America/Marigot", "Saint Martin"),
+    ("America/Martinique", "Martinique"),
+    ("America/Matamoros", "Mexico"),
+    ("America/Mazatlan", "Mexico"),
+    ("America/Menominee", "United States"),
+    ("America/Merida", "Mexico"),
+    ("America/Metlakatla", "United States"),
+    ("America/Mexico_City", "Mexico"),
+    ("America/Miquelon", "Saint Pierre and Miquelon"),
+    ("America/Moncton", "Canada"),
+    ("America/Monterrey", "Mexico"),
+    ("America/Montevideo", "Uruguay"),
+    ("America/Montserrat", "Montserrat"),
+    ("America/Nassau", "Bahamas"),
+    ("America/New_York", "United States"),
+    ("America/Nome", "United States"),
+    ("America/Noronha", "Brazil"),
+    ("America/North_Dakota/Beulah", "United States"),
+    ("America/North_Dakota/Center", "United States"),
+    ("America/North_Dakota/New_Salem", "United States"),
+    ("America/Nuuk", "Greenland"),
+    ("America/Ojinaga", "Mexico"),
+    ("America/Panama", "Panama"),
+    ("America/Paramaribo", "Suriname"),
+    ("America/Phoenix", "United States"),
+    ("America/Port-au-Prince", "Haiti"),
+    ("America/Port_of_Spain", "Trinidad and Tobago"),
+    ("America/Porto_Velho", "Brazil"),
+    ("America/Puerto_Rico", "Puerto Rico"),
+    ("America/Punta_Arenas", "Chile"),
+    ("America/Rankin_Inlet", "Canada"),
+    ("America/Recife", "Brazil"),
+    ("America/Regina", "Canada"),
+    ("America/Resolute", "Canada"),
+    ("America/Rio_Branco", "Brazil"),
+    ("America/Santarem", "Brazil"),
+    ("America/Santiago", "Chile"),
+    ("America/Santo_Domingo", "Dominican Republic"),
+    ("America/Sao_Paulo", "Brazil"),
+    ("America/Scoresbysund", "Greenland"),
+    ("America/Sitka", "United States"),
+    ("America/St_Barthelemy", "Saint Barthélemy"),
+    ("America/St_Johns", "Canada"),
+    ("America/St_Kitts", "Saint Kitts and Nevis"),
+    ("America/St_Lucia", "Saint Lucia"),
+    ("America/St_Thomas", "United States Virgin Islands"),
+    ("America/St_Vincent", "Saint Vincent and the Grenadines"),
+    ("America/Swift_Current", "Canada"),
+    ("America/Tegucigalpa", "Honduras"),
+    ("America/Thule", "Greenland"),
+    ("America/Tijuana", "Mexico"),
+    ("America/Toronto", "Canada"),
+    ("America/Tortola", "British Virgin Islands"),
+    ("America/Vancouver", "Canada"),
+    ("America/Whitehorse", "Canada"),
+    ("America/Winnipeg", "Canada"),
+    ("America/Yakutat", "United States"),
+    ("America/Yellowknife", "Canada"),
+    // Antarctica
+    ("Antarctica/Casey", "Antarctica"),
+    ("Antarctica/Davis", "Antarctica"),
+    ("Antarctica/DumontDUrville", "Antarctica"),
+    ("Antarctica/Macquarie", "Australia"),
+    ("Antarctica/Mawson", "Antarctica"),
+    ("Antarctica/McMurdo", "Antarctica"),
+    ("Antarctica/Palmer", "Antarctica"),
+    ("Antarctica/Rothera", "Antarctica"),
+    ("Antarctica/Syowa", "Antarctica"),
+    ("Antarctica/Troll", "Antarctica"),
+    ("Antarctica/Vostok", "Antarctica"),
+    // Arctic
+    ("Arctic/Longyearbyen", "Svalbard and Jan Mayen"),
+    // Asia
+    ("Asia/Aden", "Yemen"),
+    ("Asia/Almaty", "Kazakhstan"),
+    ("Asia/Amman", "Jordan"),
+    ("Asia/Anadyr", "Russia"),
+    ("Asia/Aqtau", "Kazakhstan"),
+    ("Asia/Aqtobe", "Kazakhstan"),
+    ("Asia/Ashgabat", "Turkmenistan"),
+    ("Asia/Atyrau", "Kazakhstan"),
+    ("Asia/Baghdad", "Iraq"),
+    ("Asia/Bahrain", "Bahrain"),
+    ("Asia/Baku", "Azerbaijan"),
+    ("Asia/Bangkok", "Thailand"),
+    ("Asia/Barnaul", "Russia"),
+    ("Asia/Beirut", "Lebanon"),
+    ("Asia/Bishkek", "Kyrgyzstan"),
+    ("Asia/Brunei", "Brunei"),
+    ("Asia/Chita", "Russia"),
+    ("Asia/Choibalsan", "Mongolia"),
+    ("Asia/Colombo", "Sri Lanka"),
+    ("Asia/Damascus", "Syria"),
+    ("Asia/Dhaka", "Bangladesh"),
+    ("Asia/Dili", "East Timor"),
+    ("Asia/Dubai", "United Arab Emirates"),
+    ("Asia/Dushanbe", "Tajikistan"),
+    ("Asia/Famagusta", "Cyprus"),
+    ("Asia/Gaza", "Palestine"),
+    ("Asia/Hebron", "Palestine"),
+    ("Asia/Ho_Chi_Minh", "Vietnam"),
+    ("Asia/Hong_Kong", "Hong Kong"),
+    ("Asia/Hovd", "Mongolia"),
+    ("Asia/Irkutsk", "Russia"),
+    ("Asia/Jakarta", "Indonesia"),
+    ("Asia/Jayapura", "Indonesia"),
+    ("Asia/Jerusalem", "Israel"),
+    ("Asia/Kabul", "Afghanistan"),
+    ("Asia/Kamchatka", "Russia"),
+    ("Asia/Karachi", "Pakistan"),
+    ("Asia/Kathmandu", "Nepal"),
+    ("Asia/Khandyga", "Russia"),
+    ("Asia/Kolkata", "India"),
+    ("Asia/Krasnoyarsk", "Russia"),
+    ("Asia/Kuala_Lumpur", "Malaysia"),
+    ("Asia/Kuching", "Malaysia"),
+    ("Asia/Kuwait", "Kuwait"),
+    ("Asia/Macau", "Macau"),
+    ("Asia/Magadan", "Russia"),
+    ("Asia/Makassar", "Indonesia"),
+    ("Asia/Manila", "Philippines"),
+    ("Asia/Muscat", "Oman"),
+    ("Asia/Nicosia", "Cyprus"),
+    ("Asia/Novokuznetsk", "Russia"),
+    ("Asia/Novosibirsk", "Russia"),
+    ("Asia/Omsk", "Russia"),
+    ("Asia/Oral", "Kazakhstan"),
+    ("Asia/Phnom_Penh", "Cambodia"),
+    ("Asia/Pontianak", "Indonesia"),
+    ("Asia/Pyongyang", "North Korea"),
+    ("Asia/Qatar", "Qatar"),
+    ("Asia/Qostanay", "Kazakhstan"),
+    ("Asia/Qyzylorda", "Kazakhstan"),
+    ("Asia/Riyadh", "Saudi Arabia"),
+    ("Asia/Sakhalin", "Russia"),
+    ("Asia/Samarkand", "Uzbekistan"),
+    ("Asia/Seoul", "South Korea"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Singapore", "Singapore"),
+    ("Asia/Srednekolymsk", "Russia"),
+    ("Asia/Taipei", "Taiwan"),
+    ("Asia/Tashkent", "Uzbekistan"),
+    ("Asia/Tbilisi", "Georgia"),
+    ("Asia/Tehran", "Iran"),
+    ("Asia/Thimphu", "Bhutan"),
+    ("Asia/Tokyo", "Japan"),
+    ("Asia/Tomsk", "Russia"),
+    ("Asia/Ulaanbaatar", "Mongolia"),
+    ("Asia/Urumqi", "China"),
+    ("Asia/Ust-Nera", "Russia"),
+    ("Asia/Vientiane", "Laos"),
+    ("Asia/Vladivostok", "Russia"),
+    ("Asia/Yakutsk", "Russia"),
+    ("Asia/Yangon", "Myanmar"),
+    ("Asia/Yekaterinburg", "Russia"),
+    ("Asia/Yerevan", "Armenia"),
+    // Atlantic
+    ("Atlantic/Azores", "Portugal"),
+    ("Atlantic/Bermuda", "Bermuda"),
+    ("Atlantic/Canary", "Spain"),
+    ("Atlantic/Cape_Verde", "Cabo Verde"),
+    ("Atlantic/Faroe", "Faroe Islands"),
+    ("Atlantic/Madeira", "Portugal"),
+    ("Atlantic/Reykjavik", "Iceland"),
+    ("Atlantic/South_Georgia", "South Georgia and the South Sandwich Islands"),
+    ("Atlantic/St_Helena", "Saint Helena"),
+    ("Atlantic/Stanley", "Falkland Islands"),
+    // Australia
+    ("Australia/Adelaide", "Australia"),
+    ("Australia/Brisbane", "Australia"),
+    ("Australia/Broken_Hill", "Australia"),
+    ("Australia/Darwin", "Australia"),
+    ("Australia/Eucla", "Australia"),
+    ("Australia/Hobart", "Australia"),
+    ("Australia/Lindeman", "Australia"),
+    ("Australia/Lord_Howe", "Australia"),
+    ("Australia/Melbourne", "Australia"),
+    ("Australia/Perth", "Australia"),
+    ("Australia/Sydney", "Australia"),
+    // Europe
+    ("Europe/Amsterdam", "Netherlands"),
+    ("Europe/Andorra", "Andorra"),
+    ("Europe/Astrakhan", "Russia"),
+    ("Europe/Athens", "Greece"),
+    ("Europe/Belgrade", "Serbia"),
+    ("Europe/Berlin", "Germany"),
+    ("Europe/Bratislava", "Slovakia"),
+    ("Europe/Brussels", "Belgium"),
+    ("Europe/Bucharest", "Romania"),
+    ("Europe/Budapest", "Hungary"),
+    ("Europe/Busingen", "Germany"),
+    ("Europe/Chisinau", "Moldova"),
+    ("Europe/Copenhagen", "Denmark"),
+    ("Europe/Dublin", "Ireland"),
+    ("Europe/Gibraltar", "Gibraltar"),
+    ("Europe/Guernsey", "Guernsey"),
+    ("Europe/Helsinki", "Finland"),
+    ("Europe/Isle_of_Man", "Isle of Man"),
+    ("Europe/Istanbul", "Turkey"),
+    ("Europe/Jersey", "Jersey"),
+    ("Europe/Kaliningrad", "Russia"),
+    ("Europe/Kirov", "Russia"),
+    ("Europe/Kyiv", "Ukraine"),
+    ("Europe/Lisbon", "Portugal"),
+    ("Europe/Ljubljana", "Slovenia"),
+    ("Europe/London", "United Kingdom"),
+    ("Europe/Luxembourg", "Luxembourg"),
+    ("Europe/Madrid", "Spain"),
+    ("Europe/Malta", "Malta"),
+    ("Europe/Mariehamn", "Åland Islands"),
+    ("Europe/Minsk", "Belarus"),
+    ("Europe/Monaco", "Monaco"),
+    ("Europe/Moscow", "Russia"),
+    ("Europe/Oslo", "Norway"),
+    ("Europe/Paris", "France"),
+    ("Europe/Podgorica", "Montenegro"),
+    ("Europe/Prague", "Czech Republic"),
+    ("Europe/Riga", "Latvia"),
+    ("Europe/Rome", "Italy"),
+    ("Europe/Samara", "Russia"),
+    ("Europe/San_Marino", "San Marino"),
+    ("Europe/Sarajevo", "Bosnia and Herzegovina"),
+    ("Europe/Saratov", "Russia"),
+    ("Europe/Simferopol", "Russia"),
+    ("Europe/Skopje", "North Macedonia"),
+    ("Europe/Sofia", "Bulgaria"),
+    ("Europe/Stockholm", "Sweden"),
+    ("Europe/Tallinn", "Estonia"),
+    ("Europe/Tirane", "Albania"),
+    ("Europe/Ulyanovsk", "Russia"),
+    ("Europe/Vaduz", "Liechtenstein"),
+    ("Europe/Vatican", "Vatican City"),
+    ("Europe/Vienna", "Austria"),
+    ("Europe/Vilnius", "Lithuania"),
+    ("Europe/Volgograd", "Russia"),
+    ("Europe/Warsaw", "Poland"),
+    ("Europe/Zagreb", "Croatia"),
+    ("Europe/Zurich", "Switzerland"),
+    // Indian
+    ("Indian/Antananarivo", "Madagascar"),
+    ("Indian/Chagos", "British Indian Ocean Territory"),
+    ("Indian/Christmas", "Christmas Island"),
+    ("Indian/Cocos", "Cocos (Keeling) Islands"),
+    ("Indian/Comoro", "Comoros"),
+    ("Indian/Kerguelen", "French Southern Territories"),
+    ("Indian/Mahe", "Seychelles"),
+    ("Indian/Maldives", "Maldives"),
+    ("Indian/Mauritius", "Mauritius"),
+    ("Indian/Mayotte", "Mayotte"),
+    ("Indian/Reunion", "Réunion"),
+    // Pacific
+    ("Pacific/Apia", "Samoa"),
+    ("Pacific/Auckland", "New Zealand"),
+    ("Pacific/Bougainville", "Papua New Guinea"),
+    ("Pacific/Chatham", "New Zealand"),
+    ("Pacific/Chuuk", "Micronesia"),
+    ("Pacific/Easter", "Chile"),
+    ("Pacific/Efate", "Vanuatu"),
+    ("Pacific/Fakaofo", "Tokelau"),
+    ("Pacific/Fiji", "Fiji"),
+    ("Pacific/Funafuti", "Tuvalu"),
+    ("Pacific/Galapagos", "Ecuador"),
+    ("Pacific/Gambier", "French Polynesia"),
+    ("Pacific/Guadalcanal", "Solomon Islands"),
+    ("Pacific/Guam", "Guam"),
+    ("Pacific/Honolulu", "United States"),
+    ("Pacific/Kanton", "Kiribati"),
+    ("Pacific/Kiritimati", "Kiribati"),
+    ("Pacific/Kosrae", "Micronesia"),
+    ("Pacific/Kwajalein", "Marshall Islands"),
+    ("Pacific/Majuro", "Marshall Islands"),
+    ("Pacific/Marquesas", "French Polynesia"),
+    ("Pacific/Midway", "United States"),
+    ("Pacific/Nauru", "Nauru"),
+    ("Pacific/Niue", "Niue"),
+    ("Pacific/Norfolk", "Norfolk Island"),
+    ("Pacific/Noumea", "New Caledonia"),
+    ("Pacific/Pago_Pago", "American Samoa"),
+    ("Pacific/Palau", "Palau"),
+    ("Pacific/Pitcairn", "Pitcairn Islands"),
+    ("Pacific/Pohnpei", "Micronesia"),
+    ("Pacific/Port_Moresby", "Papua New Guinea"),
+    ("Pacific/Rarotonga", "Cook Islands"),
+    ("Pacific/Saipan", "Northern Mariana Islands"),
+    ("Pacific/Tahiti", "French Polynesia"),
+    ("Pacific/Tarawa", "Kiribati"),
+    ("Pacific/Tongatapu", "Tonga"),
+    ("Pacific/Wake", "United States"),
+    ("Pacific/Wallis", "Wallis and Futuna"),
+];
+
+/// Detects the host's configured timezone the way system tools do: read
+/// `/etc/timezone`, then fall back to the `zoneinfo/<Zone>` suffix of the
+/// `/etc/localtime` symlink's target, then the `TZ` environment variable.
+/// Falls back to `"UTC"` rather than failing the build when none resolve.
+fn detect_system_timezone() -> String {
+    // Each candidate is checked against `chrono_tz::Tz` before being
+    // accepted — a POSIX-rules `TZ` (e.g. "EST5EDT,M3.2.0,M11.1.0"), a
+    // leading-colon `TZ`, or odd `/etc/timezone`/`/etc/localtime` contents
+    // must fall through to the next source rather than reach chunk191-1's
+    // validation and abort the build for a project that never set one.
+    if let Ok(contents) = fs::read_to_string("/etc/timezone") {
+        let zone = contents.trim();
+        if !zone.is_empty() && zone.parse::<chrono_tz::Tz>().is_ok() {
+            return zone.to_string();
+        }
+    }
+
+    if let Ok(target) = fs::read_link("/etc/localtime") {
+        let target_str = target.to_string_lossy();
+        if let Some(zone) = target_str
+            .split("zoneinfo/")
+            .nth(1)
+            .filter(|z| !z.is_empty() && z.parse::<chrono_tz::Tz>().is_ok())
+        {
+            return zone.to_string();
+        }
+    }
+
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.strip_prefix(':').unwrap_or(&tz);
+        if !tz.is_empty() && tz.parse::<chrono_tz::Tz>().is_ok() {
+            return tz.to_string();
+        }
+    }
+
+    "UTC".to_string()
+}
+
+/// Finds the IANA zone in `chrono_tz::TZ_VARIANTS` closest to an invalid
+/// input, by edit distance, to suggest in a build error.
+fn closest_timezone_suggestion(input: &str) -> &'static str {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .min_by_key(|tz| levenshtein_distance(input, tz.name()))
+        .map(|tz| tz.name())
+        .unwrap_or("UTC")
+}
+
+/// Classic dynamic-programming edit distance between two strings.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dp[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1).min(dp[i][j - 1] + 1).min(dp[i - 1][j - 1] + cost);
+        }
+    }
+    dp[a.len()][b.len()]
+}
+
+/// Converts a tag name into a directory-safe slug: lowercase, with any run
+/// of non-alphanumeric characters collapsed to a single `-`.
+fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut last_was_dash = false;
+    for ch in s.trim().chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+            last_was_dash = false;
+        } else if !last_was_dash && !slug.is_empty() {
+            slug.push('-');
+            last_was_dash = true;
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
 }
 
 #[cfg(test)]
@@ -1183,4 +2697,199 @@ spec:
             .join("index.html");
         assert!(project_page.exists());
     }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Backend"), "backend");
+        assert_eq!(slugify("  Needs Review!! "), "needs-review");
+        assert_eq!(slugify("v1.2_beta"), "v1-2-beta");
+    }
+
+    #[test]
+    fn test_classify_change_routes_top_level_resource_file_to_full_rebuild() {
+        let temp_dir = tempdir().unwrap();
+        let base_path = temp_dir.path().to_path_buf();
+        let use_case = BuildUseCase::new(base_path.clone(), "dist").unwrap();
+
+        let changed = base_path.join("resources").join("dev-01.yaml");
+        assert_eq!(use_case.classify_change(&changed), WatchTarget::FullRebuild);
+    }
+
+    #[test]
+    fn test_classify_change_still_scopes_project_nested_resource_file_to_project() {
+        let temp_dir = tempdir().unwrap();
+        let base_path = temp_dir.path().to_path_buf();
+        let use_case = BuildUseCase::new(base_path.clone(), "dist").unwrap();
+
+        let changed = base_path
+            .join("companies")
+            .join("test-company")
+            .join("projects")
+            .join("proj-1")
+            .join("resources")
+            .join("dev-01.yaml");
+        match use_case.classify_change(&changed) {
+            WatchTarget::Project { project_ident, .. } => assert_eq!(project_ident, "proj-1"),
+            other => panic!("Expected WatchTarget::Project, got {:?}", other),
+        }
+    }
+
+    fn make_planned_task(
+        code: &str,
+        due_in_days: i64,
+    ) -> crate::domain::task_management::task::Task<crate::domain::task_management::state::Planned> {
+        use crate::domain::task_management::{category::Category, priority::Priority, state::Planned, task::Task};
+
+        let today = chrono::Utc::now().date_naive();
+        Task::<Planned> {
+            id: uuid7::uuid7(),
+            project_code: "PROJ-1".to_string(),
+            code: code.to_string(),
+            name: format!("Task {code}"),
+            description: None,
+            state: Planned,
+            start_date: today,
+            due_date: today + chrono::Duration::days(due_in_days),
+            actual_end_date: None,
+            dependencies: vec![],
+            assigned_resources: vec![],
+            priority: Priority::Medium,
+            category: Category::default(),
+        }
+    }
+
+    #[test]
+    fn test_compute_task_urgency_due_term_caps_at_overdue_and_far_out() {
+        let today = chrono::Utc::now().date_naive();
+
+        let overdue: crate::domain::task_management::AnyTask = make_planned_task("A", -1).into();
+        let due_today: crate::domain::task_management::AnyTask = make_planned_task("B", 0).into();
+        let far_out: crate::domain::task_management::AnyTask = make_planned_task("C", 14).into();
+        let further_out: crate::domain::task_management::AnyTask = make_planned_task("D", 30).into();
+
+        let overdue_urgency = compute_task_urgency(&overdue, &[], today);
+        let due_today_urgency = compute_task_urgency(&due_today, &[], today);
+        let far_out_urgency = compute_task_urgency(&far_out, &[], today);
+        let further_out_urgency = compute_task_urgency(&further_out, &[], today);
+
+        // `days_until_due <= 0` all hit the same 12.0 due-term cap.
+        assert_eq!(overdue_urgency, due_today_urgency);
+        // `days_until_due >= 14` all hit the same 0.2 due-term floor.
+        assert_eq!(far_out_urgency, further_out_urgency);
+        // Overdue must outrank something 14 days out.
+        assert!(overdue_urgency > far_out_urgency);
+    }
+
+    #[test]
+    fn test_compute_task_urgency_blocking_task_outranks_leaf_task() {
+        let today = chrono::Utc::now().date_naive();
+
+        let blocker: crate::domain::task_management::AnyTask = make_planned_task("BLOCKER", 10).into();
+        // `leaf` depends on `blocker`, so `blocker` has a dependent and `leaf` doesn't.
+        let mut leaf_task = make_planned_task("LEAF", 10);
+        leaf_task.dependencies.push("BLOCKER".to_string());
+        let leaf: crate::domain::task_management::AnyTask = leaf_task.into();
+
+        let all_tasks = vec![blocker.clone(), leaf.clone()];
+        let blocker_urgency = compute_task_urgency(&blocker, &all_tasks, today);
+        let leaf_urgency = compute_task_urgency(&leaf, &all_tasks, today);
+
+        assert!(blocker_urgency > leaf_urgency);
+    }
+
+    #[test]
+    fn test_compute_task_urgency_in_progress_adds_exactly_the_active_term() {
+        use crate::domain::task_management::{state::InProgress, task::Task};
+
+        let today = chrono::Utc::now().date_naive();
+
+        let planned_task = make_planned_task("A", 10);
+        let in_progress_task = Task {
+            id: planned_task.id,
+            project_code: planned_task.project_code.clone(),
+            code: planned_task.code.clone(),
+            name: planned_task.name.clone(),
+            description: planned_task.description.clone(),
+            state: InProgress { progress: 0 },
+            start_date: planned_task.start_date,
+            due_date: planned_task.due_date,
+            actual_end_date: planned_task.actual_end_date,
+            dependencies: planned_task.dependencies.clone(),
+            assigned_resources: planned_task.assigned_resources.clone(),
+            priority: planned_task.priority,
+            category: planned_task.category,
+        };
+
+        let planned: crate::domain::task_management::AnyTask = planned_task.into();
+        let in_progress: crate::domain::task_management::AnyTask = in_progress_task.into();
+
+        let planned_urgency = compute_task_urgency(&planned, &[], today);
+        let in_progress_urgency = compute_task_urgency(&in_progress, &[], today);
+
+        assert_eq!(in_progress_urgency - planned_urgency, 4.0);
+    }
+
+    #[test]
+    fn test_resolve_midnight_in_timezone_advances_past_a_spring_forward_gap() {
+        let tz: chrono_tz::Tz = "America/Sao_Paulo".parse().unwrap();
+        // Brazil's DST historically began at local midnight by jumping the
+        // clock forward from 00:00 straight to 01:00, so 2018-11-04 has no
+        // valid 00:00 local time.
+        let date = chrono::NaiveDate::from_ymd_opt(2018, 11, 4).unwrap();
+        let naive_midnight = date.and_hms_opt(0, 0, 0).unwrap();
+        assert!(matches!(naive_midnight.and_local_timezone(tz), chrono::LocalResult::None));
+
+        let resolved = resolve_midnight_in_timezone(date, tz);
+
+        assert_eq!(resolved.date_naive(), date);
+        // Must land on the first valid instant after the gap, not loop forever.
+        assert!(matches!(resolved.naive_local().and_local_timezone(tz), chrono::LocalResult::Single(_)));
+        assert_eq!(resolved.naive_local().time(), chrono::NaiveTime::from_hms_opt(1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_resolve_midnight_in_timezone_resolves_ambiguous_fall_back_to_earlier_offset() {
+        // Rather than hardcoding a specific calendar date as "the" fall-back
+        // day for a given zone, scan actual tzdata for one: whichever zone
+        // and date it finds, `resolve_midnight_in_timezone` must agree with
+        // the earlier (pre-rollback) offset `chrono_tz` itself reports.
+        let candidate_zones: &[chrono_tz::Tz] = &[
+            chrono_tz::America::New_York,
+            chrono_tz::Europe::London,
+            chrono_tz::Europe::Berlin,
+            chrono_tz::America::Sao_Paulo,
+            chrono_tz::America::Santiago,
+            chrono_tz::America::Asuncion,
+            chrono_tz::America::Montevideo,
+            chrono_tz::America::Havana,
+            chrono_tz::Africa::Cairo,
+            chrono_tz::Asia::Gaza,
+            chrono_tz::Asia::Amman,
+            chrono_tz::Asia::Beirut,
+            chrono_tz::Australia::Sydney,
+            chrono_tz::Australia::Lord_Howe,
+            chrono_tz::Pacific::Auckland,
+        ];
+
+        let mut found: Option<(chrono_tz::Tz, chrono::NaiveDate, chrono::DateTime<chrono_tz::Tz>)> = None;
+        'search: for &tz in candidate_zones {
+            let mut date = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            let end = chrono::NaiveDate::from_ymd_opt(2035, 1, 1).unwrap();
+            while date < end {
+                let naive_midnight = date.and_hms_opt(0, 0, 0).unwrap();
+                if let chrono::LocalResult::Ambiguous(earlier, _later) = naive_midnight.and_local_timezone(tz) {
+                    found = Some((tz, date, earlier));
+                    break 'search;
+                }
+                date = date.succ_opt().unwrap();
+            }
+        }
+
+        let (tz, date, earlier) =
+            found.expect("expected at least one candidate zone to have a midnight DST fall-back between 1970 and 2035");
+
+        let resolved = resolve_midnight_in_timezone(date, tz);
+
+        assert_eq!(resolved, earlier);
+    }
 }
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of the task fields `task update` is about to overwrite, so an
+/// undo can restore exactly what was there before.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub due_date: Option<NaiveDate>,
+}
+
+/// A reversible mutation recorded in the journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OperationKind {
+    DeleteTask {
+        project: String,
+        code: String,
+    },
+    DeactivateResource {
+        code: String,
+        company: String,
+    },
+    LinkTask {
+        project: String,
+        from: String,
+        to: String,
+    },
+    UnlinkTask {
+        project: String,
+        from: String,
+        to: String,
+    },
+    UpdateTask {
+        project: String,
+        code: String,
+        previous: TaskSnapshot,
+    },
+}
+
+impl OperationKind {
+    /// One-line description shown to the user when undoing.
+    pub fn summary(&self) -> String {
+        match self {
+            OperationKind::DeleteTask { project, code } => format!("delete task '{}' in project '{}'", code, project),
+            OperationKind::DeactivateResource { code, .. } => format!("deactivate resource '{}'", code),
+            OperationKind::LinkTask { project, from, to } => {
+                format!("link task '{}' -> '{}' in project '{}'", from, to, project)
+            }
+            OperationKind::UnlinkTask { project, from, to } => {
+                format!("unlink task '{}' -> '{}' in project '{}'", from, to, project)
+            }
+            OperationKind::UpdateTask { project, code, .. } => format!("update task '{}' in project '{}'", code, project),
+        }
+    }
+}
+
+/// A single entry in the append-only operation journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub recorded_at: DateTime<Local>,
+    pub operation: OperationKind,
+}
+
+impl JournalEntry {
+    pub fn new(operation: OperationKind) -> Self {
+        Self {
+            recorded_at: Local::now(),
+            operation,
+        }
+    }
+}
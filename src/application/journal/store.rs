@@ -0,0 +1,136 @@
+use super::entry::JournalEntry;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Errors raised while reading or writing the operation journal.
+#[derive(Debug)]
+pub enum JournalError {
+    Io(String),
+    Serialization(String),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JournalError::Io(msg) => write!(f, "journal I/O error: {}", msg),
+            JournalError::Serialization(msg) => write!(f, "journal serialization error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+/// Append-only, YAML-backed log of reversible mutations.
+///
+/// Stored at `<workspace_root>/.ttr/journal.yaml` so it survives across
+/// invocations and can be synced alongside the rest of the workspace.
+pub struct JournalStore {
+    path: PathBuf,
+}
+
+impl JournalStore {
+    pub fn new(workspace_root: &Path) -> Self {
+        Self {
+            path: workspace_root.join(".ttr").join("journal.yaml"),
+        }
+    }
+
+    pub fn load(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path).map_err(|e| JournalError::Io(e.to_string()))?;
+        if content.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        serde_yaml::from_str(&content).map_err(|e| JournalError::Serialization(e.to_string()))
+    }
+
+    fn save(&self, entries: &[JournalEntry]) -> Result<(), JournalError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| JournalError::Io(e.to_string()))?;
+        }
+
+        let yaml = serde_yaml::to_string(entries).map_err(|e| JournalError::Serialization(e.to_string()))?;
+        fs::write(&self.path, yaml).map_err(|e| JournalError::Io(e.to_string()))
+    }
+
+    /// Appends `entry` to the journal.
+    pub fn append(&self, entry: JournalEntry) -> Result<(), JournalError> {
+        let mut entries = self.load()?;
+        entries.push(entry);
+        self.save(&entries)
+    }
+
+    /// Removes and returns up to `count` entries from the end of the journal,
+    /// most-recent first.
+    pub fn pop_last(&self, count: usize) -> Result<Vec<JournalEntry>, JournalError> {
+        let mut entries = self.load()?;
+        let take = count.min(entries.len());
+        let popped: Vec<JournalEntry> = entries.split_off(entries.len() - take).into_iter().rev().collect();
+        self.save(&entries)?;
+        Ok(popped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::journal::entry::OperationKind;
+
+    #[test]
+    fn append_and_load_round_trips() {
+        let dir = std::env::temp_dir().join(format!("ttr-journal-test-{:?}", std::thread::current().id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let store = JournalStore::new(&dir);
+        store
+            .append(JournalEntry::new(OperationKind::DeleteTask {
+                project: "PROJ-1".to_string(),
+                code: "TSK-1".to_string(),
+            }))
+            .unwrap();
+
+        let entries = store.load().unwrap();
+        assert_eq!(entries.len(), 1);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn pop_last_removes_most_recent_first() {
+        let dir = std::env::temp_dir().join(format!("ttr-journal-test-pop-{:?}", std::thread::current().id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let store = JournalStore::new(&dir);
+        store
+            .append(JournalEntry::new(OperationKind::DeleteTask {
+                project: "PROJ-1".to_string(),
+                code: "FIRST".to_string(),
+            }))
+            .unwrap();
+        store
+            .append(JournalEntry::new(OperationKind::DeleteTask {
+                project: "PROJ-1".to_string(),
+                code: "SECOND".to_string(),
+            }))
+            .unwrap();
+
+        let popped = store.pop_last(1).unwrap();
+        assert_eq!(popped.len(), 1);
+        match &popped[0].operation {
+            OperationKind::DeleteTask { code, .. } => assert_eq!(code, "SECOND"),
+            _ => panic!("unexpected operation"),
+        }
+
+        let remaining = store.load().unwrap();
+        assert_eq!(remaining.len(), 1);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -2,9 +2,12 @@ pub mod build_use_case;
 pub mod initialize_repository_use_case;
 
 pub mod create;
+pub mod journal;
 pub mod list;
 pub mod project;
 pub mod report;
 pub mod resource;
+pub mod scheduling;
+pub mod sync;
 pub mod task;
 pub mod validate;
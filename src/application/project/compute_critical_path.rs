@@ -0,0 +1,239 @@
+use crate::application::errors::AppError;
+use crate::application::scheduling::resolve::{topological_order, SchedulingError, TaskNode};
+use crate::application::shared::code_resolver::CodeResolverTrait;
+use crate::domain::project_management::repository::{ProjectRepository, ProjectRepositoryWithId};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CriticalPathError {
+    ProjectNotFound(String),
+    CycleDetected(Vec<String>),
+    RepositoryError(AppError),
+}
+
+impl fmt::Display for CriticalPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CriticalPathError::ProjectNotFound(code) => write!(f, "Project with code '{}' not found.", code),
+            CriticalPathError::CycleDetected(codes) => {
+                write!(f, "Dependency cycle detected among tasks: {}", codes.join(" -> "))
+            }
+            CriticalPathError::RepositoryError(err) => write!(f, "Repository error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CriticalPathError {}
+
+impl From<AppError> for CriticalPathError {
+    fn from(err: AppError) -> Self {
+        CriticalPathError::RepositoryError(err)
+    }
+}
+
+impl From<crate::domain::shared::errors::DomainError> for CriticalPathError {
+    fn from(err: crate::domain::shared::errors::DomainError) -> Self {
+        CriticalPathError::RepositoryError(err.into())
+    }
+}
+
+impl From<SchedulingError> for CriticalPathError {
+    fn from(err: SchedulingError) -> Self {
+        match err {
+            SchedulingError::CycleDetected(codes) => CriticalPathError::CycleDetected(codes),
+        }
+    }
+}
+
+/// A task's position in the project schedule: earliest/latest start and
+/// finish (in days from the project start), and the slack between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskTiming {
+    pub code: String,
+    pub earliest_start: i64,
+    pub earliest_finish: i64,
+    pub latest_start: i64,
+    pub latest_finish: i64,
+    pub slack: i64,
+}
+
+/// The full critical-path report for a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalPathReport {
+    pub timings: Vec<TaskTiming>,
+    /// Tasks with zero slack, in schedule order — the chain that drives the
+    /// project's end date.
+    pub critical_path: Vec<String>,
+}
+
+/// Computes the critical path of a project's task graph using the standard
+/// two-pass CPM algorithm: a forward pass for earliest start/finish, then a
+/// backward pass from the project end for latest start/finish.
+pub struct ComputeCriticalPathUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    project_repository: PR,
+    code_resolver: CR,
+}
+
+impl<PR, CR> ComputeCriticalPathUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    pub fn new(project_repository: PR, code_resolver: CR) -> Self {
+        Self {
+            project_repository,
+            code_resolver,
+        }
+    }
+
+    pub fn execute(&self, project_code: &str) -> Result<CriticalPathReport, CriticalPathError> {
+        // 1. Resolve project code to ID
+        let project_id = self
+            .code_resolver
+            .resolve_project_code(project_code)
+            .map_err(|e| CriticalPathError::RepositoryError(AppError::from(e)))?;
+
+        // 2. Load the project and build the scheduler's task nodes
+        let project = self
+            .project_repository
+            .find_by_id(&project_id)?
+            .ok_or_else(|| CriticalPathError::ProjectNotFound(project_code.to_string()))?;
+
+        let nodes: Vec<TaskNode> = project
+            .tasks()
+            .values()
+            .map(|task| TaskNode {
+                code: task.code().to_string(),
+                dependencies: task.dependencies().to_vec(),
+                start_date: *task.start_date(),
+                due_date: *task.due_date(),
+            })
+            .collect();
+
+        Ok(compute_critical_path(&nodes)?)
+    }
+}
+
+/// Duration, in days, between a node's `start_date` and `due_date`.
+fn duration(node: &TaskNode) -> i64 {
+    (node.due_date - node.start_date).num_days()
+}
+
+pub(crate) fn compute_critical_path(nodes: &[TaskNode]) -> Result<CriticalPathReport, SchedulingError> {
+    let order = topological_order(nodes)?;
+    let by_code: HashMap<&str, &TaskNode> = nodes.iter().map(|n| (n.code.as_str(), n)).collect();
+
+    // Forward pass: earliest start/finish.
+    let mut earliest_finish: HashMap<&str, i64> = HashMap::new();
+    let mut earliest_start: HashMap<&str, i64> = HashMap::new();
+
+    for code in &order {
+        let node = by_code[code.as_str()];
+        let es = node
+            .dependencies
+            .iter()
+            .filter_map(|dep| earliest_finish.get(dep.as_str()))
+            .copied()
+            .max()
+            .unwrap_or(0);
+        let ef = es + duration(node);
+        earliest_start.insert(code.as_str(), es);
+        earliest_finish.insert(code.as_str(), ef);
+    }
+
+    let project_end = earliest_finish.values().copied().max().unwrap_or(0);
+
+    // Successors map, needed for the backward pass.
+    let mut successors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for node in nodes {
+        for dep in &node.dependencies {
+            successors.entry(dep.as_str()).or_default().push(node.code.as_str());
+        }
+    }
+
+    // Backward pass: latest finish/start, walking the topological order in reverse.
+    let mut latest_finish: HashMap<&str, i64> = HashMap::new();
+    let mut latest_start: HashMap<&str, i64> = HashMap::new();
+
+    for code in order.iter().rev() {
+        let node = by_code[code.as_str()];
+        let lf = successors
+            .get(code.as_str())
+            .and_then(|succs| succs.iter().filter_map(|s| latest_start.get(s)).copied().min())
+            .unwrap_or(project_end);
+        let ls = lf - duration(node);
+        latest_finish.insert(code.as_str(), lf);
+        latest_start.insert(code.as_str(), ls);
+    }
+
+    let mut timings = Vec::with_capacity(order.len());
+    let mut critical_path = Vec::new();
+
+    for code in &order {
+        let es = earliest_start[code.as_str()];
+        let ef = earliest_finish[code.as_str()];
+        let ls = latest_start[code.as_str()];
+        let lf = latest_finish[code.as_str()];
+        let slack = ls - es;
+
+        if slack == 0 {
+            critical_path.push(code.clone());
+        }
+
+        timings.push(TaskTiming {
+            code: code.clone(),
+            earliest_start: es,
+            earliest_finish: ef,
+            latest_start: ls,
+            latest_finish: lf,
+            slack,
+        });
+    }
+
+    Ok(CriticalPathReport { timings, critical_path })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn node(code: &str, deps: &[&str], duration_days: i64) -> TaskNode {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        TaskNode {
+            code: code.to_string(),
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            start_date: start,
+            due_date: start + chrono::Duration::days(duration_days),
+        }
+    }
+
+    #[test]
+    fn identifies_the_critical_path_through_the_longest_chain() {
+        let nodes = vec![
+            node("A", &[], 2),
+            node("B", &["A"], 5),
+            node("C", &["A"], 1),
+            node("D", &["B", "C"], 2),
+        ];
+
+        let report = compute_critical_path(&nodes).unwrap();
+
+        assert_eq!(report.critical_path, vec!["A", "B", "D"]);
+        let c = report.timings.iter().find(|t| t.code == "C").unwrap();
+        assert_eq!(c.slack, 4);
+    }
+
+    #[test]
+    fn rejects_cyclic_graphs() {
+        let nodes = vec![node("A", &["B"], 1), node("B", &["A"], 1)];
+
+        let result = compute_critical_path(&nodes);
+        assert!(matches!(result, Err(SchedulingError::CycleDetected(_))));
+    }
+}
@@ -0,0 +1,324 @@
+use crate::application::errors::AppError;
+use crate::application::scheduling::resolve::{compute_schedule, topological_order, ScheduledTask, SchedulingError, TaskNode};
+use crate::application::shared::code_resolver::CodeResolverTrait;
+use crate::domain::project_management::repository::{ProjectRepository, ProjectRepositoryWithId};
+use crate::domain::task_management::any_task::AnyTask;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ScheduleAppError {
+    ProjectNotFound(String),
+    Scheduling(SchedulingError),
+    RepositoryError(AppError),
+}
+
+impl fmt::Display for ScheduleAppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleAppError::ProjectNotFound(code) => write!(f, "Project with code '{}' not found.", code),
+            ScheduleAppError::Scheduling(err) => write!(f, "{}", err),
+            ScheduleAppError::RepositoryError(err) => write!(f, "Repository error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleAppError {}
+
+impl From<AppError> for ScheduleAppError {
+    fn from(err: AppError) -> Self {
+        ScheduleAppError::RepositoryError(err)
+    }
+}
+
+impl From<crate::domain::shared::errors::DomainError> for ScheduleAppError {
+    fn from(err: crate::domain::shared::errors::DomainError) -> Self {
+        ScheduleAppError::RepositoryError(err.into())
+    }
+}
+
+impl From<SchedulingError> for ScheduleAppError {
+    fn from(err: SchedulingError) -> Self {
+        ScheduleAppError::Scheduling(err)
+    }
+}
+
+/// Loads every task in a project and produces its topologically ordered,
+/// date-propagated execution plan.
+pub struct ScheduleProjectUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    project_repository: PR,
+    code_resolver: CR,
+}
+
+impl<PR, CR> ScheduleProjectUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    pub fn new(project_repository: PR, code_resolver: CR) -> Self {
+        Self {
+            project_repository,
+            code_resolver,
+        }
+    }
+
+    pub fn execute(&self, project_code: &str) -> Result<Vec<ScheduledTask>, ScheduleAppError> {
+        let (_, nodes) = self.load_task_nodes(project_code)?;
+        Ok(compute_schedule(&nodes)?)
+    }
+
+    /// Returns the project's own tasks in a valid execution order — every
+    /// dependency before its dependents, ties broken by `start_date` then
+    /// code — for callers that want the tasks themselves rather than a
+    /// dated schedule (e.g. rendering a Gantt row order).
+    pub fn execute_task_order(&self, project_code: &str) -> Result<Vec<AnyTask>, ScheduleAppError> {
+        let (project, nodes) = self.load_task_nodes(project_code)?;
+        let order = topological_order(&nodes)?;
+
+        Ok(order
+            .into_iter()
+            .filter_map(|code| project.tasks().get(&code).cloned())
+            .collect())
+    }
+
+    fn load_task_nodes(
+        &self,
+        project_code: &str,
+    ) -> Result<(crate::domain::project_management::any_project::AnyProject, Vec<TaskNode>), ScheduleAppError> {
+        // 1. Resolve project code to ID
+        let project_id = self
+            .code_resolver
+            .resolve_project_code(project_code)
+            .map_err(|e| ScheduleAppError::RepositoryError(AppError::from(e)))?;
+
+        // 2. Use ID for internal operation
+        let project = self
+            .project_repository
+            .find_by_id(&project_id)?
+            .ok_or_else(|| ScheduleAppError::ProjectNotFound(project_code.to_string()))?;
+
+        let nodes: Vec<TaskNode> = project
+            .tasks()
+            .values()
+            .map(|task| TaskNode {
+                code: task.code().to_string(),
+                dependencies: task.dependencies().to_vec(),
+                start_date: *task.start_date(),
+                due_date: *task.due_date(),
+            })
+            .collect();
+
+        Ok((project, nodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::project_management::{any_project::AnyProject, builder::ProjectBuilder};
+    use crate::domain::task_management::{state::Planned, task::Task};
+    use chrono::NaiveDate;
+    use std::{cell::RefCell, collections::HashMap};
+    use uuid7::uuid7;
+
+    struct MockProjectRepository {
+        projects: RefCell<HashMap<String, AnyProject>>,
+    }
+
+    impl ProjectRepository for MockProjectRepository {
+        fn save(&self, project: AnyProject) -> Result<(), AppError> {
+            self.projects.borrow_mut().insert(project.id().to_string(), project);
+            Ok(())
+        }
+        fn find_by_code(&self, code: &str) -> Result<Option<AnyProject>, AppError> {
+            Ok(self.projects.borrow().values().find(|p| p.code() == code).cloned())
+        }
+        fn load(&self) -> Result<AnyProject, AppError> {
+            unimplemented!()
+        }
+        fn find_all(&self) -> Result<Vec<AnyProject>, AppError> {
+            unimplemented!()
+        }
+        fn get_next_code(&self) -> Result<String, AppError> {
+            unimplemented!()
+        }
+    }
+
+    impl ProjectRepositoryWithId for MockProjectRepository {
+        fn find_by_id(&self, id: &str) -> Result<Option<AnyProject>, AppError> {
+            Ok(self.projects.borrow().get(id).cloned())
+        }
+    }
+
+    struct MockCodeResolver {
+        project_codes: RefCell<HashMap<String, String>>,
+    }
+
+    impl MockCodeResolver {
+        fn new() -> Self {
+            Self {
+                project_codes: RefCell::new(HashMap::new()),
+            }
+        }
+
+        fn add_project(&self, code: &str, id: &str) {
+            self.project_codes.borrow_mut().insert(code.to_string(), id.to_string());
+        }
+    }
+
+    impl CodeResolverTrait for MockCodeResolver {
+        fn resolve_company_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("company", "Not implemented in mock"))
+        }
+
+        fn resolve_project_code(&self, code: &str) -> Result<String, AppError> {
+            self.project_codes.borrow().get(code).cloned().ok_or_else(|| {
+                AppError::validation_error("project", format!("Project '{}' not found", code))
+            })
+        }
+
+        fn resolve_resource_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("resource", "Not implemented in mock"))
+        }
+
+        fn resolve_task_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("task", "Not implemented in mock"))
+        }
+
+        fn validate_company_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_company_code(code)?;
+            Ok(())
+        }
+
+        fn validate_project_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_project_code(code)?;
+            Ok(())
+        }
+
+        fn validate_resource_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_resource_code(code)?;
+            Ok(())
+        }
+
+        fn validate_task_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_task_code(code)?;
+            Ok(())
+        }
+    }
+
+    fn planned_task(code: &str, deps: &[&str], start_date: NaiveDate, due_date: NaiveDate) -> AnyTask {
+        AnyTask::Planned(Task::<Planned> {
+            id: uuid7(),
+            project_code: "PROJ-1".to_string(),
+            code: code.to_string(),
+            name: code.to_string(),
+            description: None,
+            state: Planned,
+            start_date,
+            due_date,
+            actual_end_date: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            assigned_resources: vec![],
+        })
+    }
+
+    fn project_with_tasks(tasks: Vec<AnyTask>) -> AnyProject {
+        let mut builder = ProjectBuilder::new()
+            .code("PROJ-1".to_string())
+            .name("Test Project".to_string())
+            .company_code("COMP-001".to_string())
+            .created_by("test-user".to_string());
+        for task in tasks {
+            builder = builder.add_task(task);
+        }
+        builder.build().unwrap().into()
+    }
+
+    #[test]
+    fn execute_returns_a_dated_schedule_in_dependency_order() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let due = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let project = project_with_tasks(vec![
+            planned_task("A", &["B"], start, due),
+            planned_task("B", &[], start, due),
+        ]);
+        let project_id = project.id().to_string();
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_id.clone(), project)])),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", &project_id);
+
+        let use_case = ScheduleProjectUseCase::new(project_repo, code_resolver);
+
+        let schedule = use_case.execute("PROJ-1").unwrap();
+        let codes: Vec<&str> = schedule.iter().map(|t| t.code.as_str()).collect();
+        assert_eq!(codes, vec!["B", "A"]);
+    }
+
+    #[test]
+    fn execute_returns_project_not_found_for_unknown_project() {
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::new()),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", "some-id");
+
+        let use_case = ScheduleProjectUseCase::new(project_repo, code_resolver);
+
+        let err = use_case.execute("PROJ-1").unwrap_err();
+        assert!(matches!(err, ScheduleAppError::ProjectNotFound(code) if code == "PROJ-1"));
+    }
+
+    #[test]
+    fn execute_returns_repository_error_when_code_resolution_fails() {
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::new()),
+        };
+        let code_resolver = MockCodeResolver::new();
+
+        let use_case = ScheduleProjectUseCase::new(project_repo, code_resolver);
+
+        let err = use_case.execute("PROJ-1").unwrap_err();
+        assert!(matches!(err, ScheduleAppError::RepositoryError(_)));
+    }
+
+    #[test]
+    fn execute_task_order_returns_the_project_s_tasks_in_dependency_order() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let due = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let project = project_with_tasks(vec![
+            planned_task("A", &["B"], start, due),
+            planned_task("B", &[], start, due),
+        ]);
+        let project_id = project.id().to_string();
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_id.clone(), project)])),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", &project_id);
+
+        let use_case = ScheduleProjectUseCase::new(project_repo, code_resolver);
+
+        let order = use_case.execute_task_order("PROJ-1").unwrap();
+        let codes: Vec<&str> = order.iter().map(|t| t.code()).collect();
+        assert_eq!(codes, vec!["B", "A"]);
+    }
+
+    #[test]
+    fn execute_task_order_returns_project_not_found_for_unknown_project() {
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::new()),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", "some-id");
+
+        let use_case = ScheduleProjectUseCase::new(project_repo, code_resolver);
+
+        let err = use_case.execute_task_order("PROJ-1").unwrap_err();
+        assert!(matches!(err, ScheduleAppError::ProjectNotFound(code) if code == "PROJ-1"));
+    }
+}
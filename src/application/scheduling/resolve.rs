@@ -0,0 +1,230 @@
+//! Dependency-graph resolution shared by `ttr project schedule` (and, in
+//! future, other dependency-aware commands).
+//!
+//! Builds a DAG from each task's `dependencies` list and runs a single
+//! Kahn's-algorithm pass: in-degree counts are maintained, zero-in-degree
+//! nodes are repeatedly emitted, and whatever is left once the queue drains
+//! is the cycle.
+
+use chrono::NaiveDate;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// A task as seen by the scheduler: just enough to build the DAG and
+/// propagate dates, independent of `AnyTask`'s state machine.
+#[derive(Debug, Clone)]
+pub struct TaskNode {
+    pub code: String,
+    pub dependencies: Vec<String>,
+    pub start_date: NaiveDate,
+    pub due_date: NaiveDate,
+}
+
+/// Errors raised while resolving or scheduling a dependency graph.
+#[derive(Debug, PartialEq)]
+pub enum SchedulingError {
+    /// The leftover nodes once Kahn's algorithm's queue drains — a cycle.
+    CycleDetected(Vec<String>),
+}
+
+impl fmt::Display for SchedulingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulingError::CycleDetected(codes) => {
+                write!(f, "Dependency cycle detected among tasks: {}", codes.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchedulingError {}
+
+/// A task placed in the execution plan with its computed earliest dates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledTask {
+    pub code: String,
+    pub earliest_start: NaiveDate,
+    pub earliest_finish: NaiveDate,
+    /// Set when `due_date` precedes the earliest finish of one of its dependencies.
+    pub conflict: Option<String>,
+}
+
+/// Returns `tasks` ordered so that every dependency appears before its
+/// dependents, or the offending cycle if the graph isn't a DAG.
+pub fn topological_order(tasks: &[TaskNode]) -> Result<Vec<String>, SchedulingError> {
+    let by_code: HashMap<&str, &TaskNode> = tasks.iter().map(|t| (t.code.as_str(), t)).collect();
+    let mut in_degree: HashMap<&str, usize> = tasks.iter().map(|t| (t.code.as_str(), 0)).collect();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for task in tasks {
+        for dep in &task.dependencies {
+            if let Some(degree) = in_degree.get_mut(task.code.as_str()) {
+                *degree += 1;
+            }
+            dependents.entry(dep.as_str()).or_default().push(task.code.as_str());
+        }
+    }
+
+    // Ties among simultaneously-ready tasks break by start_date, then code,
+    // so the emitted order is deterministic and reads like a schedule.
+    let sort_by_start_then_code = |codes: &mut Vec<&str>| {
+        codes.sort_unstable_by_key(|&code| (by_code[code].start_date, code));
+    };
+
+    let mut ready: Vec<&str> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&code, _)| code)
+        .collect();
+    sort_by_start_then_code(&mut ready);
+    let mut queue: VecDeque<&str> = ready.into();
+
+    let mut remaining_in_degree = in_degree.clone();
+    let mut order = Vec::with_capacity(tasks.len());
+
+    while let Some(code) = queue.pop_front() {
+        order.push(code.to_string());
+
+        if let Some(deps) = dependents.get(code) {
+            let mut newly_ready: Vec<&str> = Vec::new();
+            for &dependent in deps {
+                if let Some(degree) = remaining_in_degree.get_mut(dependent) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        newly_ready.push(dependent);
+                    }
+                }
+            }
+            sort_by_start_then_code(&mut newly_ready);
+            for node in newly_ready {
+                queue.push_back(node);
+            }
+        }
+    }
+
+    if order.len() != tasks.len() {
+        let mut cycle: Vec<String> = remaining_in_degree
+            .into_iter()
+            .filter(|(_, degree)| *degree > 0)
+            .map(|(code, _)| code.to_string())
+            .collect();
+        cycle.sort();
+        return Err(SchedulingError::CycleDetected(cycle));
+    }
+
+    Ok(order)
+}
+
+/// Computes earliest start/finish dates for every task by propagating each
+/// task's own duration forward from the tasks with no predecessors, flagging
+/// any task whose `due_date` falls before a dependency's earliest finish.
+pub fn compute_schedule(tasks: &[TaskNode]) -> Result<Vec<ScheduledTask>, SchedulingError> {
+    let order = topological_order(tasks)?;
+    let by_code: HashMap<&str, &TaskNode> = tasks.iter().map(|t| (t.code.as_str(), t)).collect();
+    let mut finishes: HashMap<String, NaiveDate> = HashMap::new();
+    let mut scheduled = Vec::with_capacity(order.len());
+
+    for code in order {
+        let task = by_code[code.as_str()];
+        let duration = task.due_date - task.start_date;
+
+        let mut earliest_start = task.start_date;
+        let mut conflict = None;
+
+        for dep in &task.dependencies {
+            if let Some(&dep_finish) = finishes.get(dep).as_ref() {
+                if dep_finish > earliest_start {
+                    earliest_start = dep_finish;
+                }
+                if task.due_date < dep_finish {
+                    conflict = Some(format!(
+                        "due date {} precedes dependency '{}' finishing {}",
+                        task.due_date, dep, dep_finish
+                    ));
+                }
+            }
+        }
+
+        let earliest_finish = earliest_start + duration;
+        finishes.insert(task.code.clone(), earliest_finish);
+
+        scheduled.push(ScheduledTask {
+            code: task.code.clone(),
+            earliest_start,
+            earliest_finish,
+            conflict,
+        });
+    }
+
+    Ok(scheduled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(code: &str, deps: &[&str], start: (i32, u32, u32), due: (i32, u32, u32)) -> TaskNode {
+        TaskNode {
+            code: code.to_string(),
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            start_date: NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap(),
+            due_date: NaiveDate::from_ymd_opt(due.0, due.1, due.2).unwrap(),
+        }
+    }
+
+    #[test]
+    fn orders_tasks_before_their_dependents() {
+        let tasks = vec![
+            node("B", &["A"], (2026, 1, 1), (2026, 1, 5)),
+            node("A", &[], (2026, 1, 1), (2026, 1, 3)),
+        ];
+
+        let order = topological_order(&tasks).unwrap();
+        assert_eq!(order, vec!["A".to_string(), "B".to_string()]);
+    }
+
+    #[test]
+    fn breaks_ties_among_ready_tasks_by_start_date_then_code() {
+        let tasks = vec![
+            node("C", &[], (2026, 1, 3), (2026, 1, 4)),
+            node("A", &[], (2026, 1, 1), (2026, 1, 2)),
+            node("B", &[], (2026, 1, 1), (2026, 1, 2)),
+        ];
+
+        let order = topological_order(&tasks).unwrap();
+        assert_eq!(order, vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+    }
+
+    #[test]
+    fn detects_cycles_via_leftover_nodes() {
+        let tasks = vec![node("A", &["B"], (2026, 1, 1), (2026, 1, 3)), node("B", &["A"], (2026, 1, 1), (2026, 1, 3))];
+
+        let result = topological_order(&tasks);
+        assert!(matches!(result, Err(SchedulingError::CycleDetected(_))));
+    }
+
+    #[test]
+    fn propagates_earliest_dates_forward() {
+        let tasks = vec![
+            node("A", &[], (2026, 1, 1), (2026, 1, 5)),
+            node("B", &["A"], (2026, 1, 1), (2026, 1, 10)),
+        ];
+
+        let schedule = compute_schedule(&tasks).unwrap();
+        let b = schedule.iter().find(|s| s.code == "B").unwrap();
+        assert_eq!(b.earliest_start, NaiveDate::from_ymd_opt(2026, 1, 5).unwrap());
+        assert!(b.conflict.is_none());
+    }
+
+    #[test]
+    fn flags_due_date_before_dependency_finish() {
+        let tasks = vec![
+            node("A", &[], (2026, 1, 1), (2026, 1, 10)),
+            node("B", &["A"], (2026, 1, 1), (2026, 1, 3)),
+        ];
+
+        let schedule = compute_schedule(&tasks).unwrap();
+        let b = schedule.iter().find(|s| s.code == "B").unwrap();
+        assert!(b.conflict.is_some());
+    }
+}
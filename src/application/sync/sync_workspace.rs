@@ -0,0 +1,173 @@
+use crate::infrastructure::vcs::{VcsError, VcsPort};
+use std::fmt;
+use std::path::Path;
+
+/// Errors returned by [`SyncWorkspaceUseCase::execute`].
+#[derive(Debug)]
+pub enum SyncAppError {
+    /// Nothing changed since the last sync; there is no new commit to make.
+    NothingToCommit,
+    /// Local and remote history have diverged.
+    Conflict(String),
+    /// Any other underlying VCS failure.
+    Vcs(VcsError),
+}
+
+impl fmt::Display for SyncAppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncAppError::NothingToCommit => write!(f, "Nothing to sync; workspace is already up to date."),
+            SyncAppError::Conflict(msg) => write!(f, "Sync conflict: {}", msg),
+            SyncAppError::Vcs(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SyncAppError {}
+
+impl From<VcsError> for SyncAppError {
+    fn from(err: VcsError) -> Self {
+        match err {
+            VcsError::Conflict(msg) => SyncAppError::Conflict(msg),
+            other => SyncAppError::Vcs(other),
+        }
+    }
+}
+
+/// Input for a single `sync` run.
+pub struct SyncArgs {
+    pub remote: String,
+    pub message: Option<String>,
+    pub pull: bool,
+    pub push: bool,
+}
+
+/// Outcome of a successful `sync` run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncReport {
+    pub commit_id: String,
+    pub files_changed: usize,
+    pub pulled: bool,
+    pub pushed: bool,
+}
+
+/// Stages, commits, and optionally pulls/pushes the workspace's YAML store.
+pub struct SyncWorkspaceUseCase<V: VcsPort> {
+    vcs: V,
+}
+
+impl<V: VcsPort> SyncWorkspaceUseCase<V> {
+    pub fn new(vcs: V) -> Self {
+        Self { vcs }
+    }
+
+    pub fn execute(&self, workspace_root: &Path, args: SyncArgs) -> Result<SyncReport, SyncAppError> {
+        if args.pull {
+            self.vcs.pull(workspace_root, &args.remote)?;
+        }
+
+        let files_changed = self.vcs.stage_all(workspace_root)?;
+        if files_changed == 0 {
+            return Err(SyncAppError::NothingToCommit);
+        }
+
+        let message = args
+            .message
+            .unwrap_or_else(|| format!("sync: update {} file(s)", files_changed));
+        let commit_id = self.vcs.commit(workspace_root, &message)?;
+
+        if args.push {
+            self.vcs.push(workspace_root, &args.remote)?;
+        }
+
+        Ok(SyncReport {
+            commit_id,
+            files_changed,
+            pulled: args.pull,
+            pushed: args.push,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct MockVcs {
+        staged: RefCell<usize>,
+        pulled: RefCell<bool>,
+        pushed: RefCell<bool>,
+        fail_pull: bool,
+    }
+
+    impl VcsPort for MockVcs {
+        fn stage_all(&self, _workspace_root: &Path) -> Result<usize, VcsError> {
+            Ok(*self.staged.borrow())
+        }
+
+        fn commit(&self, _workspace_root: &Path, _message: &str) -> Result<String, VcsError> {
+            Ok("deadbeef".to_string())
+        }
+
+        fn pull(&self, _workspace_root: &Path, _remote: &str) -> Result<(), VcsError> {
+            if self.fail_pull {
+                return Err(VcsError::Conflict("diverged".to_string()));
+            }
+            *self.pulled.borrow_mut() = true;
+            Ok(())
+        }
+
+        fn push(&self, _workspace_root: &Path, _remote: &str) -> Result<(), VcsError> {
+            *self.pushed.borrow_mut() = true;
+            Ok(())
+        }
+    }
+
+    fn args(remote: &str, pull: bool, push: bool) -> SyncArgs {
+        SyncArgs {
+            remote: remote.to_string(),
+            message: None,
+            pull,
+            push,
+        }
+    }
+
+    #[test]
+    fn commits_staged_changes() {
+        let vcs = MockVcs {
+            staged: RefCell::new(3),
+            ..Default::default()
+        };
+        let use_case = SyncWorkspaceUseCase::new(vcs);
+
+        let report = use_case.execute(Path::new("."), args("origin", false, false)).unwrap();
+        assert_eq!(report.files_changed, 3);
+        assert_eq!(report.commit_id, "deadbeef");
+        assert!(!report.pulled);
+        assert!(!report.pushed);
+    }
+
+    #[test]
+    fn reports_nothing_to_commit_when_clean() {
+        let vcs = MockVcs::default();
+        let use_case = SyncWorkspaceUseCase::new(vcs);
+
+        let result = use_case.execute(Path::new("."), args("origin", false, false));
+        assert!(matches!(result, Err(SyncAppError::NothingToCommit)));
+    }
+
+    #[test]
+    fn surfaces_pull_conflicts() {
+        let vcs = MockVcs {
+            staged: RefCell::new(1),
+            fail_pull: true,
+            ..Default::default()
+        };
+        let use_case = SyncWorkspaceUseCase::new(vcs);
+
+        let result = use_case.execute(Path::new("."), args("origin", true, false));
+        assert!(matches!(result, Err(SyncAppError::Conflict(_))));
+    }
+}
@@ -9,6 +9,10 @@ use std::fmt;
 pub enum DescribeAppError {
     ProjectNotFound(String),
     TaskNotFound(String),
+    /// A `dependencies` entry references a task code absent from the project.
+    DanglingDependency(String),
+    /// An `assigned_resources` entry cannot be resolved to a known resource.
+    UnresolvedResource(String),
     RepositoryError(AppError),
 }
 
@@ -17,6 +21,12 @@ impl fmt::Display for DescribeAppError {
         match self {
             DescribeAppError::ProjectNotFound(code) => write!(f, "Project with code '{}' not found.", code),
             DescribeAppError::TaskNotFound(code) => write!(f, "Task with code '{}' not found in project.", code),
+            DescribeAppError::DanglingDependency(code) => {
+                write!(f, "Dependency task with code '{}' not found in project.", code)
+            }
+            DescribeAppError::UnresolvedResource(code) => {
+                write!(f, "Assigned resource with code '{}' could not be resolved.", code)
+            }
             DescribeAppError::RepositoryError(err) => write!(f, "Repository error: {}", err),
         }
     }
@@ -79,6 +89,50 @@ where
 
         Ok(task)
     }
+
+    /// Like [`execute`](Self::execute), but instead of stopping at the first
+    /// problem it collects every problem it can find — the project and task
+    /// existing, every `dependencies` entry resolving within the project,
+    /// and every `assigned_resources` entry resolving through the code
+    /// resolver — and returns them all together.
+    pub fn execute_validated(&self, project_code: &str, task_code: &str) -> Result<AnyTask, Vec<DescribeAppError>> {
+        let project_id = self
+            .code_resolver
+            .resolve_project_code(project_code)
+            .map_err(|e| vec![DescribeAppError::RepositoryError(e)])?;
+
+        let project = self
+            .project_repository
+            .find_by_id(&project_id)
+            .map_err(|e| vec![DescribeAppError::from(e)])?
+            .ok_or_else(|| vec![DescribeAppError::ProjectNotFound(project_code.to_string())])?;
+
+        let task = project
+            .tasks()
+            .get(task_code)
+            .cloned()
+            .ok_or_else(|| vec![DescribeAppError::TaskNotFound(task_code.to_string())])?;
+
+        let mut errors = Vec::new();
+
+        for dependency in task.dependencies() {
+            if !project.tasks().contains_key(dependency) {
+                errors.push(DescribeAppError::DanglingDependency(dependency.clone()));
+            }
+        }
+
+        for resource in task.assigned_resources() {
+            if self.code_resolver.resolve_resource_code(resource).is_err() {
+                errors.push(DescribeAppError::UnresolvedResource(resource.clone()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(task)
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,12 +153,20 @@ mod tests {
     }
 
     struct MockCodeResolver {
-        // Mock doesn't need to resolve anything for DescribeTaskUseCase
+        resolvable_resources: std::collections::HashSet<String>,
     }
 
     impl MockCodeResolver {
         fn new() -> Self {
-            Self {}
+            Self {
+                resolvable_resources: std::collections::HashSet::new(),
+            }
+        }
+
+        fn with_resolvable_resources(resources: &[&str]) -> Self {
+            Self {
+                resolvable_resources: resources.iter().map(|r| r.to_string()).collect(),
+            }
         }
     }
 
@@ -117,8 +179,12 @@ mod tests {
             Ok("mock-project-id".to_string())
         }
 
-        fn resolve_resource_code(&self, _code: &str) -> Result<String, AppError> {
-            Err(AppError::validation_error("resource", "Not implemented in mock"))
+        fn resolve_resource_code(&self, code: &str) -> Result<String, AppError> {
+            if self.resolvable_resources.contains(code) {
+                Ok(code.to_string())
+            } else {
+                Err(AppError::validation_error("resource", format!("Resource '{}' not found", code)))
+            }
         }
 
         fn resolve_task_code(&self, _code: &str) -> Result<String, AppError> {
@@ -248,4 +314,39 @@ mod tests {
 
         assert!(matches!(result, Err(DescribeAppError::ProjectNotFound(_))));
     }
+
+    #[test]
+    fn test_execute_validated_success() {
+        let project_code = "PROJ-1";
+        let task_code = "TSK-1";
+        let project = create_test_project(project_code, vec![create_test_task(task_code)]);
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_code.to_string(), project)])),
+        };
+        let code_resolver = MockCodeResolver::with_resolvable_resources(&["dev-1"]);
+        let use_case = DescribeTaskUseCase::new(project_repo, code_resolver);
+
+        let result = use_case.execute_validated(project_code, task_code);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_execute_validated_collects_all_problems() {
+        let project_code = "PROJ-1";
+        let task_code = "TSK-1";
+        let task = create_test_task(task_code).add_dependency("TSK-MISSING".to_string());
+        let project = create_test_project(project_code, vec![task]);
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_code.to_string(), project)])),
+        };
+        // "dev-1" (the task's assigned resource) is deliberately left unresolvable.
+        let code_resolver = MockCodeResolver::new();
+        let use_case = DescribeTaskUseCase::new(project_repo, code_resolver);
+
+        let errors = use_case.execute_validated(project_code, task_code).unwrap_err();
+
+        assert!(errors.iter().any(|e| matches!(e, DescribeAppError::DanglingDependency(c) if c == "TSK-MISSING")));
+        assert!(errors.iter().any(|e| matches!(e, DescribeAppError::UnresolvedResource(c) if c == "dev-1")));
+    }
 }
@@ -22,8 +22,8 @@ impl fmt::Display for LinkAppError {
             LinkAppError::ProjectNotFound(code) => write!(f, "Project with code '{}' not found.", code),
             LinkAppError::TaskNotFound(code) => write!(f, "Task with code '{}' not found.", code),
             LinkAppError::DependencyNotFound(code) => write!(f, "Dependency task with code '{}' not found.", code),
-            LinkAppError::CircularDependencyDetected(tasks) => {
-                write!(f, "Circular dependency detected between tasks: {:?}", tasks)
+            LinkAppError::CircularDependencyDetected(chain) => {
+                write!(f, "Circular dependency detected: {}", chain.join(" -> "))
             }
             LinkAppError::AppError(message) => write!(f, "Domain error: {}", message),
             LinkAppError::RepositoryError(err) => write!(f, "Repository error: {}", err),
@@ -93,16 +93,18 @@ where
         }
 
         // 4. Check for circular dependencies.
-        // We perform a DFS traversal starting from the dependency to see if it eventually leads back to the original task.
-        let mut stack = vec![dependency_code.to_string()];
+        // We perform a DFS traversal starting from the dependency to see if it eventually leads back to the
+        // original task, carrying the path walked so far so a detected cycle can report the full chain
+        // (e.g. `["A", "B", "C", "A"]`) instead of just the two endpoints being linked.
+        let mut stack = vec![(
+            dependency_code.to_string(),
+            vec![task_code.to_string(), dependency_code.to_string()],
+        )];
         let mut visited = std::collections::HashSet::new();
 
-        while let Some(current_code) = stack.pop() {
+        while let Some((current_code, path)) = stack.pop() {
             if current_code == task_code {
-                return Err(LinkAppError::CircularDependencyDetected(vec![
-                    task_code.to_string(),
-                    dependency_code.to_string(),
-                ]));
+                return Err(LinkAppError::CircularDependencyDetected(path));
             }
 
             // To avoid infinite loops on existing cycles, we only process each node once.
@@ -119,7 +121,9 @@ where
                     AnyTask::Cancelled(t) => &t.dependencies,
                 };
                 for dep in dependencies {
-                    stack.push(dep.clone());
+                    let mut next_path = path.clone();
+                    next_path.push(dep.clone());
+                    stack.push((dep.clone(), next_path));
                 }
             }
         }
@@ -358,6 +362,38 @@ mod tests {
         assert!(matches!(result, Err(LinkAppError::CircularDependencyDetected(_))));
     }
 
+    #[test]
+    fn test_link_task_reports_full_cycle_chain_for_indirect_cycles() {
+        // B depends on A (B -> A), C depends on B (C -> B)
+        let task_a = create_test_task("A");
+        let mut task_b = create_test_task("B");
+        if let AnyTask::Planned(t) = &mut task_b {
+            t.dependencies.push("A".to_string());
+        }
+        let mut task_c = create_test_task("C");
+        if let AnyTask::Planned(t) = &mut task_c {
+            t.dependencies.push("B".to_string());
+        }
+
+        let project = setup_test_project(vec![task_a, task_b, task_c]);
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project.code().to_string(), project)])),
+            should_fail_save: false,
+        };
+        let code_resolver = MockCodeResolver { should_fail: false };
+        let use_case = LinkTaskUseCase::new(project_repo, code_resolver);
+
+        // Try to create dependency A -> C, which would close the cycle A -> C -> B -> A
+        let result = use_case.execute("PROJ-1", "A", "C");
+
+        match result {
+            Err(LinkAppError::CircularDependencyDetected(chain)) => {
+                assert_eq!(chain, vec!["A".to_string(), "C".to_string(), "B".to_string(), "A".to_string()]);
+            }
+            other => panic!("Expected CircularDependencyDetected, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_link_task_with_different_task_states() {
         // Create tasks with different states to test the match statement
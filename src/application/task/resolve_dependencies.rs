@@ -0,0 +1,307 @@
+use crate::application::errors::AppError;
+use crate::application::shared::code_resolver::CodeResolverTrait;
+use crate::domain::project_management::repository::{ProjectRepository, ProjectRepositoryWithId};
+use std::collections::HashSet;
+use std::fmt;
+
+/// The chain of task codes visited while walking a dependency graph, most
+/// recent last — e.g. `["TSK-1", "TSK-2", "TSK-1"]` for a cycle.
+pub type DepChain = Vec<String>;
+
+#[derive(Debug)]
+pub enum ResolveDependenciesError {
+    ProjectNotFound(String),
+    /// A dependency chain revisits a task still on the stack.
+    DependencyCycle(DepChain),
+    /// A dependency chain references a task code absent from the project.
+    DependencyNotFound(DepChain),
+    RepositoryError(AppError),
+}
+
+impl fmt::Display for ResolveDependenciesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveDependenciesError::ProjectNotFound(code) => write!(f, "Project with code '{}' not found.", code),
+            ResolveDependenciesError::DependencyCycle(chain) => {
+                write!(f, "Dependency cycle detected: {}", chain.join(" -> "))
+            }
+            ResolveDependenciesError::DependencyNotFound(chain) => {
+                write!(f, "Dependency not found: {}", chain.join(" -> "))
+            }
+            ResolveDependenciesError::RepositoryError(err) => write!(f, "Repository error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ResolveDependenciesError {}
+
+impl From<AppError> for ResolveDependenciesError {
+    fn from(err: AppError) -> Self {
+        ResolveDependenciesError::RepositoryError(err)
+    }
+}
+
+impl From<crate::domain::shared::errors::DomainError> for ResolveDependenciesError {
+    fn from(err: crate::domain::shared::errors::DomainError) -> Self {
+        ResolveDependenciesError::RepositoryError(err.into())
+    }
+}
+
+/// Expands the full transitive closure of a task's dependencies within its
+/// project, excluding the task itself, with every dependency listed before
+/// the tasks that depend on it.
+pub struct ResolveTaskDependenciesUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    project_repository: PR,
+    code_resolver: CR,
+}
+
+impl<PR, CR> ResolveTaskDependenciesUseCase<PR, CR>
+where
+    PR: ProjectRepository + ProjectRepositoryWithId,
+    CR: CodeResolverTrait,
+{
+    pub fn new(project_repository: PR, code_resolver: CR) -> Self {
+        Self {
+            project_repository,
+            code_resolver,
+        }
+    }
+
+    pub fn execute(&self, project_code: &str, task_code: &str) -> Result<Vec<String>, ResolveDependenciesError> {
+        // 1. Resolve project code to ID
+        let project_id = self
+            .code_resolver
+            .resolve_project_code(project_code)
+            .map_err(|e| ResolveDependenciesError::RepositoryError(AppError::from(e)))?;
+
+        // 2. Load the project and index its tasks by code
+        let project = self
+            .project_repository
+            .find_by_id(&project_id)?
+            .ok_or_else(|| ResolveDependenciesError::ProjectNotFound(project_code.to_string()))?;
+
+        let tasks = project.tasks();
+
+        // 3. Iterative DFS over the dependency graph, tracking the current
+        // stack so a revisited on-stack node reconstructs the cycle chain.
+        let mut order = Vec::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut on_stack: HashSet<String> = HashSet::new();
+        let mut stack: Vec<(String, usize)> = vec![(task_code.to_string(), 0)];
+        on_stack.insert(task_code.to_string());
+
+        while let Some((code, dep_index)) = stack.pop() {
+            let task = tasks.get(&code).ok_or_else(|| {
+                let mut chain: Vec<String> = stack.iter().map(|(c, _)| c.clone()).collect();
+                chain.push(code.clone());
+                ResolveDependenciesError::DependencyNotFound(chain)
+            })?;
+
+            let dependencies = task.dependencies();
+
+            if dep_index >= dependencies.len() {
+                on_stack.remove(&code);
+                if visited.insert(code.clone()) && code != task_code {
+                    order.push(code);
+                }
+                continue;
+            }
+
+            // Revisit this node for its remaining dependencies once the
+            // current one has been fully explored.
+            stack.push((code.clone(), dep_index + 1));
+
+            let dependency = dependencies[dep_index].clone();
+            if on_stack.contains(&dependency) {
+                let mut chain: Vec<String> = stack.iter().map(|(c, _)| c.clone()).collect();
+                chain.push(dependency);
+                return Err(ResolveDependenciesError::DependencyCycle(chain));
+            }
+            if !visited.contains(&dependency) {
+                on_stack.insert(dependency.clone());
+                stack.push((dependency, 0));
+            }
+        }
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::project_management::{any_project::AnyProject, builder::ProjectBuilder};
+    use crate::domain::task_management::{any_task::AnyTask, state::Planned, task::Task};
+    use chrono::NaiveDate;
+    use std::{cell::RefCell, collections::HashMap};
+    use uuid7::uuid7;
+
+    struct MockProjectRepository {
+        projects: RefCell<HashMap<String, AnyProject>>,
+    }
+
+    impl ProjectRepository for MockProjectRepository {
+        fn save(&self, project: AnyProject) -> Result<(), AppError> {
+            self.projects.borrow_mut().insert(project.id().to_string(), project);
+            Ok(())
+        }
+        fn find_by_code(&self, code: &str) -> Result<Option<AnyProject>, AppError> {
+            Ok(self.projects.borrow().values().find(|p| p.code() == code).cloned())
+        }
+        fn load(&self) -> Result<AnyProject, AppError> {
+            unimplemented!()
+        }
+        fn find_all(&self) -> Result<Vec<AnyProject>, AppError> {
+            unimplemented!()
+        }
+        fn get_next_code(&self) -> Result<String, AppError> {
+            unimplemented!()
+        }
+    }
+
+    impl ProjectRepositoryWithId for MockProjectRepository {
+        fn find_by_id(&self, id: &str) -> Result<Option<AnyProject>, AppError> {
+            Ok(self.projects.borrow().get(id).cloned())
+        }
+    }
+
+    struct MockCodeResolver {
+        project_codes: RefCell<HashMap<String, String>>,
+    }
+
+    impl MockCodeResolver {
+        fn new() -> Self {
+            Self {
+                project_codes: RefCell::new(HashMap::new()),
+            }
+        }
+
+        fn add_project(&self, code: &str, id: &str) {
+            self.project_codes.borrow_mut().insert(code.to_string(), id.to_string());
+        }
+    }
+
+    impl CodeResolverTrait for MockCodeResolver {
+        fn resolve_company_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("company", "Not implemented in mock"))
+        }
+
+        fn resolve_project_code(&self, code: &str) -> Result<String, AppError> {
+            self.project_codes.borrow().get(code).cloned().ok_or_else(|| {
+                AppError::validation_error("project", format!("Project '{}' not found", code))
+            })
+        }
+
+        fn resolve_resource_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("resource", "Not implemented in mock"))
+        }
+
+        fn resolve_task_code(&self, _code: &str) -> Result<String, AppError> {
+            Err(AppError::validation_error("task", "Not implemented in mock"))
+        }
+
+        fn validate_company_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_company_code(code)?;
+            Ok(())
+        }
+
+        fn validate_project_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_project_code(code)?;
+            Ok(())
+        }
+
+        fn validate_resource_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_resource_code(code)?;
+            Ok(())
+        }
+
+        fn validate_task_code(&self, code: &str) -> Result<(), AppError> {
+            self.resolve_task_code(code)?;
+            Ok(())
+        }
+    }
+
+    fn planned_task(code: &str, deps: &[&str]) -> AnyTask {
+        AnyTask::Planned(Task::<Planned> {
+            id: uuid7(),
+            project_code: "PROJ-1".to_string(),
+            code: code.to_string(),
+            name: code.to_string(),
+            description: None,
+            state: Planned,
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            due_date: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+            actual_end_date: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+            assigned_resources: vec![],
+        })
+    }
+
+    fn project_with_tasks(tasks: Vec<AnyTask>) -> AnyProject {
+        let mut builder = ProjectBuilder::new()
+            .code("PROJ-1".to_string())
+            .name("Test Project".to_string())
+            .company_code("COMP-001".to_string())
+            .created_by("test-user".to_string());
+        for task in tasks {
+            builder = builder.add_task(task);
+        }
+        builder.build().unwrap().into()
+    }
+
+    #[test]
+    fn resolves_transitive_dependencies_dependencies_first() {
+        let project = project_with_tasks(vec![
+            planned_task("A", &["B"]),
+            planned_task("B", &["C"]),
+            planned_task("C", &[]),
+        ]);
+        let project_id = project.id().to_string();
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_id.clone(), project)])),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", &project_id);
+
+        let use_case = ResolveTaskDependenciesUseCase::new(project_repo, code_resolver);
+
+        let result = use_case.execute("PROJ-1", "A").unwrap();
+        assert_eq!(result, vec!["C".to_string(), "B".to_string()]);
+    }
+
+    #[test]
+    fn detects_cycle_and_reports_chain() {
+        let project = project_with_tasks(vec![planned_task("A", &["B"]), planned_task("B", &["A"])]);
+        let project_id = project.id().to_string();
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_id.clone(), project)])),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", &project_id);
+
+        let use_case = ResolveTaskDependenciesUseCase::new(project_repo, code_resolver);
+
+        let err = use_case.execute("PROJ-1", "A").unwrap_err();
+        assert!(matches!(err, ResolveDependenciesError::DependencyCycle(_)));
+    }
+
+    #[test]
+    fn reports_missing_dependency_chain() {
+        let project = project_with_tasks(vec![planned_task("A", &["MISSING"])]);
+        let project_id = project.id().to_string();
+        let project_repo = MockProjectRepository {
+            projects: RefCell::new(HashMap::from([(project_id.clone(), project)])),
+        };
+        let code_resolver = MockCodeResolver::new();
+        code_resolver.add_project("PROJ-1", &project_id);
+
+        let use_case = ResolveTaskDependenciesUseCase::new(project_repo, code_resolver);
+
+        let err = use_case.execute("PROJ-1", "A").unwrap_err();
+        assert!(matches!(err, ResolveDependenciesError::DependencyNotFound(_)));
+    }
+}
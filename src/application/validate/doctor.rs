@@ -0,0 +1,170 @@
+use super::business_rules::ValidateBusinessRulesUseCase;
+use super::data_integrity::ValidateDataIntegrityUseCase;
+use super::entities::ValidateEntitiesUseCase;
+use super::types::{ValidationResult, ValidationSeverity};
+use crate::application::errors::AppError;
+use crate::domain::company_management::repository::CompanyRepository;
+use crate::domain::company_settings::repository::ConfigRepository;
+use crate::domain::project_management::repository::{ProjectRepository, ProjectRepositoryDiagnostics};
+use crate::domain::resource_management::repository::ResourceRepository;
+
+/// Schema version supported by this build of the binary. Bumped whenever the
+/// on-disk YAML shape changes in a way that older/newer tooling should be warned about.
+pub const SUPPORTED_CONFIG_SCHEMA_VERSION: &str = "v1alpha1";
+
+/// Aggregate health report produced by `ttr doctor`.
+///
+/// This never mutates the workspace; it only reads the existing manifests and
+/// reports what it finds, similar in spirit to the validation use cases it
+/// builds on but aimed at a single "is this workspace healthy" summary.
+#[derive(Debug, Clone)]
+pub struct DoctorReport {
+    pub workspace_root: String,
+    pub manager_name: Option<String>,
+    pub default_timezone: Option<String>,
+    pub work_hours: Option<(String, String)>,
+    pub companies_found: usize,
+    pub projects_found: usize,
+    pub resources_found: usize,
+    pub tasks_found: usize,
+    pub findings: Vec<ValidationResult>,
+}
+
+impl DoctorReport {
+    pub fn has_errors(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|f| f.level == ValidationSeverity::Error)
+    }
+}
+
+pub struct DoctorUseCase<P, R, C, CFG>
+where
+    P: ProjectRepository + ProjectRepositoryDiagnostics,
+    R: ResourceRepository,
+    C: CompanyRepository,
+    CFG: ConfigRepository,
+{
+    project_repository: P,
+    resource_repository: R,
+    company_repository: C,
+    config_repository: CFG,
+    workspace_root: String,
+}
+
+impl<P, R, C, CFG> DoctorUseCase<P, R, C, CFG>
+where
+    P: ProjectRepository + ProjectRepositoryDiagnostics,
+    R: ResourceRepository,
+    C: CompanyRepository,
+    CFG: ConfigRepository,
+{
+    pub fn new(
+        project_repository: P,
+        resource_repository: R,
+        company_repository: C,
+        config_repository: CFG,
+        workspace_root: String,
+    ) -> Self {
+        Self {
+            project_repository,
+            resource_repository,
+            company_repository,
+            config_repository,
+            workspace_root,
+        }
+    }
+
+    pub fn execute(&self) -> Result<DoctorReport, AppError> {
+        let mut findings = Vec::new();
+
+        // 1. Config / manager data.
+        let (manager_name, default_timezone, work_hours) = match self.config_repository.load() {
+            Ok((config, _path)) => {
+                if config.default_timezone.trim().is_empty() {
+                    findings.push(ValidationResult::warning(
+                        "DOCTOR-001".to_string(),
+                        "Workspace config has no timezone configured.".to_string(),
+                    ));
+                }
+                let hours = match (&config.work_hours_start, &config.work_hours_end) {
+                    (Some(start), Some(end)) => Some((start.clone(), end.clone())),
+                    _ => {
+                        findings.push(ValidationResult::warning(
+                            "DOCTOR-002".to_string(),
+                            "Workspace config has no working hours configured.".to_string(),
+                        ));
+                        None
+                    }
+                };
+                (Some(config.manager_name), Some(config.default_timezone), hours)
+            }
+            Err(e) => {
+                findings.push(ValidationResult::error(
+                    "DOCTOR-000".to_string(),
+                    format!("Could not load config.yaml: {}", e),
+                ));
+                (None, None, None)
+            }
+        };
+
+        // 2. Schema version check. The workspace doesn't yet stamp a schema
+        // version on config.yaml, so this only records the version this
+        // binary supports as an info finding.
+        findings.push(ValidationResult::info(
+            "DOCTOR-003".to_string(),
+            format!(
+                "Binary supports config schema version '{}'.",
+                SUPPORTED_CONFIG_SCHEMA_VERSION
+            ),
+        ));
+
+        // 3. Counts + integrity/entity/business-rule findings, reusing the
+        // existing validation use cases instead of re-walking the tree.
+        let companies_found = self.company_repository.find_all()?.len();
+        let resources_found = self.resource_repository.find_all()?.len();
+        let projects = self.project_repository.find_all()?;
+        let projects_found = projects.len();
+        let tasks_found: usize = projects.iter().map(|p| p.tasks().len()).sum();
+
+        // 4. `find_all` above silently skips any manifest that fails to
+        // parse, so a malformed project/task YAML would otherwise never show
+        // up anywhere. Re-scan the raw files to surface those as findings.
+        for (path, error) in self.project_repository.find_invalid_manifests()? {
+            findings.push(ValidationResult::error(
+                "DOCTOR-004".to_string(),
+                format!("Could not parse project manifest '{}': {}", path, error),
+            ));
+        }
+
+        let data_integrity =
+            ValidateDataIntegrityUseCase::new(&self.project_repository, &self.resource_repository, &self.company_repository)
+                .execute()?;
+        findings.extend(data_integrity);
+
+        let entities =
+            ValidateEntitiesUseCase::new(&self.project_repository, &self.resource_repository, &self.company_repository)
+                .execute()?;
+        findings.extend(entities);
+
+        let business_rules = ValidateBusinessRulesUseCase::new(
+            &self.project_repository,
+            &self.resource_repository,
+            &self.company_repository,
+        )
+        .execute()?;
+        findings.extend(business_rules);
+
+        Ok(DoctorReport {
+            workspace_root: self.workspace_root.clone(),
+            manager_name,
+            default_timezone,
+            work_hours,
+            companies_found,
+            projects_found,
+            resources_found,
+            tasks_found,
+            findings,
+        })
+    }
+}
@@ -12,6 +12,9 @@ pub struct Config {
     pub work_hours_start: Option<String>,
     pub work_hours_end: Option<String>,
     pub work_days: Vec<WorkDay>,
+    /// How `BuildUseCase` orders projects and tasks on the generated site:
+    /// `"date"`, `"name"`, `"status"`, `"priority"`, or `"none"` (default).
+    pub sort_by: Option<String>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -73,6 +76,7 @@ impl Config {
                 WorkDay::Thursday,
                 WorkDay::Friday,
             ],
+            sort_by: None,
             created_at: Some(now),
             updated_at: Some(now),
         }
@@ -234,6 +238,7 @@ mod tests {
             work_hours_start: None,
             work_hours_end: None,
             work_days: vec![],
+            sort_by: None,
             created_at: None,
             updated_at: None,
         };
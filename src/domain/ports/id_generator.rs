@@ -31,8 +31,10 @@ pub trait IdGeneratorPort: Send + Sync {
     /// Validate an ID format
     fn validate_id(&self, id: &str) -> bool;
 
-    /// Get the ID type
-    fn get_id_type(&self) -> IdType;
+    /// Infers the [`IdType`] an already-generated `id` looks like, by shape
+    /// (e.g. a 36-character UUID string vs. a 10-character short ID) rather
+    /// than by tracking which method produced it.
+    fn get_id_type(&self, id: &str) -> IdType;
 }
 
 /// ID types supported by the generator
@@ -66,3 +66,16 @@ pub trait ProjectRepositoryWithId: ProjectRepository {
     /// * `Err(DomainError)` if an error occurred during search
     fn find_by_id(&self, id: &str) -> DomainResult<Option<AnyProject>>;
 }
+
+/// Extension trait for repositories that can report *why* a manifest was
+/// excluded from [`ProjectRepository::find_all`], instead of silently
+/// skipping it.
+///
+/// `find_all` only returns what parsed successfully, by design, so tools like
+/// `ttr doctor` that need to flag a malformed `project.yaml` have no way to
+/// see it through that method alone.
+pub trait ProjectRepositoryDiagnostics {
+    /// Scans every project manifest on disk and returns the ones that failed
+    /// to parse, paired with a human-readable description of the failure.
+    fn find_invalid_manifests(&self) -> DomainResult<Vec<(String, String)>>;
+}
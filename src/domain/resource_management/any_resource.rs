@@ -114,6 +114,16 @@ impl AnyResource {
         Ok(inactive_resource)
     }
 
+    /// Reactivates an inactive resource back to `Available`. Only `Inactive`
+    /// resources can be reactivated — mirrors `Inactive::can_reactivate`.
+    pub fn activate(self) -> Result<AnyResource, String> {
+        match self {
+            AnyResource::Inactive(r) => Ok(r.activate().into()),
+            AnyResource::Available(_) => Err("Resource is already active.".to_string()),
+            AnyResource::Assigned(_) => Err("Resource is already active.".to_string()),
+        }
+    }
+
     // --- Setters for updating fields ---
 
     pub fn set_name(&mut self, name: String) {
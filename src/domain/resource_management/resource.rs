@@ -255,6 +255,27 @@ impl Resource<Assigned> {
     }
 }
 
+impl Resource<Inactive> {
+    #[allow(dead_code)]
+    pub fn activate(self) -> Resource<Available> {
+        Resource {
+            id: self.id,
+            code: self.code,
+            name: self.name,
+            email: self.email,
+            resource_type: self.resource_type,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            vacations: self.vacations,
+            time_off_balance: self.time_off_balance,
+            time_off_history: self.time_off_history,
+            wip_limits: self.wip_limits,
+            task_assignments: self.task_assignments,
+            state: Available,
+        }
+    }
+}
+
 impl<S: ResourceState> Display for Resource<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
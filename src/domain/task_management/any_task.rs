@@ -169,6 +169,16 @@ impl AnyTask {
         }
     }
 
+    pub fn priority(&self) -> crate::domain::task_management::priority::Priority {
+        match self {
+            AnyTask::Planned(t) => t.priority,
+            AnyTask::InProgress(t) => t.priority,
+            AnyTask::Blocked(t) => t.priority,
+            AnyTask::Completed(t) => t.priority,
+            AnyTask::Cancelled(t) => t.priority,
+        }
+    }
+
     // --- Zero-copy accessors ---
 
     // Nota: Task não tem campos estimated_hours e actual_hours
@@ -5,8 +5,15 @@
 
 use crate::domain::ports::id_generator::{IdGeneratorPort, IdType};
 use crate::domain::shared::errors::{DomainError, DomainResult};
+use chrono::Local;
+use rand::Rng;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+/// Alphabet used by [`StandardIdGeneratorAdapter::generate_short_id`] — unambiguous
+/// uppercase letters and digits (no `0`/`O`/`1`/`I`).
+const SHORT_ID_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const SHORT_ID_LEN: usize = 10;
+
 /// Standard ID generator adapter
 pub struct StandardIdGeneratorAdapter {
     counter: AtomicU64,
@@ -18,6 +25,14 @@ impl StandardIdGeneratorAdapter {
             counter: AtomicU64::new(1),
         }
     }
+
+    /// Draws `len` characters from `alphabet` using the OS RNG.
+    fn random_string(alphabet: &[u8], len: usize) -> String {
+        let mut rng = rand::thread_rng();
+        (0..len)
+            .map(|_| alphabet[rng.gen_range(0..alphabet.len())] as char)
+            .collect()
+    }
 }
 
 impl Default for StandardIdGeneratorAdapter {
@@ -36,20 +51,25 @@ impl IdGeneratorPort for StandardIdGeneratorAdapter {
     }
 
     fn generate_uuid_v4(&self) -> String {
-        uuid7::uuid7().to_string() // Using v7 as fallback since v4 is not available
-    }
+        let mut bytes = [0u8; 16];
+        rand::thread_rng().fill(&mut bytes);
 
-    fn generate_short_id(&self) -> String {
-        // Simple short ID generation without external dependencies
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        use std::time::{SystemTime, UNIX_EPOCH};
+        // RFC 4122: set version (4) and variant (RFC 4122) bits.
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
 
-        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        format!(
+            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            bytes[0], bytes[1], bytes[2], bytes[3],
+            bytes[4], bytes[5],
+            bytes[6], bytes[7],
+            bytes[8], bytes[9],
+            bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        )
+    }
 
-        let mut hasher = DefaultHasher::new();
-        timestamp.hash(&mut hasher);
-        format!("{:x}", hasher.finish())[..8].to_string()
+    fn generate_short_id(&self) -> String {
+        Self::random_string(SHORT_ID_ALPHABET, SHORT_ID_LEN)
     }
 
     fn generate_numeric_id(&self) -> u64 {
@@ -62,24 +82,184 @@ impl IdGeneratorPort for StandardIdGeneratorAdapter {
     }
 
     fn generate_code_with_format(&self, format: &str) -> DomainResult<String> {
-        // Simple implementation - in a real scenario, this would be more sophisticated
-        if format.contains("{}") {
-            let numeric_id = self.generate_numeric_id();
-            Ok(format.replace("{}", &numeric_id.to_string()))
+        let mut result = String::with_capacity(format.len());
+        let mut chars = format.char_indices().peekable();
+
+        while let Some((start, ch)) = chars.next() {
+            if ch != '{' {
+                result.push(ch);
+                continue;
+            }
+
+            let end = loop {
+                match chars.next() {
+                    Some((idx, '}')) => break idx,
+                    Some(_) => continue,
+                    None => {
+                        return Err(DomainError::ValidationError {
+                            field: "format".to_string(),
+                            message: format!("Unterminated token starting at position {}", start),
+                        });
+                    }
+                }
+            };
+            let token = &format[start + 1..end];
+
+            result.push_str(&self.render_token(token)?);
+        }
+
+        Ok(result)
+    }
+
+    fn validate_id(&self, id: &str) -> bool {
+        if id.is_empty() || id.len() > 255 {
+            return false;
+        }
+
+        match self.get_id_type(id) {
+            IdType::Uuid | IdType::UuidV7 | IdType::UuidV4 => uuid7::Uuid::parse(id).is_ok(),
+            IdType::Short => id.len() == SHORT_ID_LEN && id.bytes().all(|b| SHORT_ID_ALPHABET.contains(&b)),
+            IdType::Numeric => !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()),
+            IdType::Code | IdType::Custom(_) => {
+                id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            }
+        }
+    }
+
+    fn get_id_type(&self, id: &str) -> IdType {
+        if uuid7::Uuid::parse(id).is_ok() {
+            IdType::Uuid
+        } else if id.len() == SHORT_ID_LEN && id.bytes().all(|b| SHORT_ID_ALPHABET.contains(&b)) {
+            IdType::Short
+        } else if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) {
+            IdType::Numeric
         } else {
-            Err(DomainError::ValidationError {
+            IdType::Code
+        }
+    }
+}
+
+impl StandardIdGeneratorAdapter {
+    /// Renders a single `{token}` from `generate_code_with_format`.
+    fn render_token(&self, token: &str) -> DomainResult<String> {
+        if token.is_empty() {
+            return Ok(self.generate_numeric_id().to_string());
+        }
+
+        if let Some(width) = token.strip_prefix("seq:") {
+            let width: usize = width.parse().map_err(|_| DomainError::ValidationError {
                 field: "format".to_string(),
-                message: "Format must contain {} placeholder".to_string(),
-            })
+                message: format!("Invalid width in token '{{{}}}': expected a number", token),
+            })?;
+            return Ok(format!("{:0width$}", self.generate_numeric_id(), width = width));
         }
+
+        if token == "date" {
+            return Ok(Local::now().format("%Y%m%d").to_string());
+        }
+
+        if let Some(len) = token.strip_prefix("rand:") {
+            let len: usize = len.parse().map_err(|_| DomainError::ValidationError {
+                field: "format".to_string(),
+                message: format!("Invalid length in token '{{{}}}': expected a number", token),
+            })?;
+            return Ok(Self::random_string(SHORT_ID_ALPHABET, len));
+        }
+
+        Err(DomainError::ValidationError {
+            field: "format".to_string(),
+            message: format!("Unknown format token '{{{}}}'", token),
+        })
     }
+}
 
-    fn validate_id(&self, id: &str) -> bool {
-        // Basic validation - check if it's not empty and has reasonable length
-        !id.is_empty() && id.len() <= 255
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_uuid_v7_produces_a_valid_uuid() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let id = adapter.generate_uuid_v7();
+
+        assert!(uuid7::Uuid::parse(&id).is_ok());
+        assert!(adapter.validate_id(&id));
+        assert_eq!(adapter.get_id_type(&id), IdType::Uuid);
     }
 
-    fn get_id_type(&self) -> IdType {
-        IdType::UuidV7
+    #[test]
+    fn generate_uuid_v4_produces_a_valid_uuid() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let id = adapter.generate_uuid_v4();
+
+        assert!(uuid7::Uuid::parse(&id).is_ok());
+        assert!(adapter.validate_id(&id));
+    }
+
+    #[test]
+    fn generate_short_id_has_expected_length_and_alphabet() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let id = adapter.generate_short_id();
+
+        assert_eq!(id.len(), SHORT_ID_LEN);
+        assert!(id.bytes().all(|b| SHORT_ID_ALPHABET.contains(&b)));
+        assert!(adapter.validate_id(&id));
+        assert_eq!(adapter.get_id_type(&id), IdType::Short);
+    }
+
+    #[test]
+    fn generate_numeric_id_increments() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let first = adapter.generate_numeric_id();
+        let second = adapter.generate_numeric_id();
+
+        assert_eq!(second, first + 1);
+        assert_eq!(adapter.get_id_type(&first.to_string()), IdType::Numeric);
+    }
+
+    #[test]
+    fn generate_code_appends_zero_padded_numeric_suffix() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let code = adapter.generate_code("PROJ").unwrap();
+
+        assert!(code.starts_with("PROJ-"));
+        assert!(adapter.validate_id(&code));
+        assert_eq!(adapter.get_id_type(&code), IdType::Code);
+    }
+
+    #[test]
+    fn generate_code_with_format_renders_seq_date_and_rand_tokens() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let code = adapter.generate_code_with_format("TASK-{seq:4}-{rand:3}").unwrap();
+
+        let parts: Vec<&str> = code.split('-').collect();
+        assert_eq!(parts[0], "TASK");
+        assert_eq!(parts[1].len(), 4);
+        assert!(parts[1].chars().all(|c| c.is_ascii_digit()));
+        assert_eq!(parts[2].len(), 3);
+    }
+
+    #[test]
+    fn generate_code_with_format_rejects_unknown_token() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let result = adapter.generate_code_with_format("BAD-{bogus}");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn generate_code_with_format_rejects_unterminated_token() {
+        let adapter = StandardIdGeneratorAdapter::new();
+        let result = adapter.generate_code_with_format("BAD-{seq:4");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn validate_id_rejects_empty_and_oversized_ids() {
+        let adapter = StandardIdGeneratorAdapter::new();
+
+        assert!(!adapter.validate_id(""));
+        assert!(!adapter.validate_id(&"a".repeat(256)));
     }
 }
@@ -42,6 +42,8 @@ pub struct ConfigSpec {
     pub locale: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vacation_rules: Option<VacationRulesManifest>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
 }
 
 fn default_timezone() -> String {
@@ -65,6 +67,7 @@ impl ConfigManifest {
                 default_task_duration: None,
                 locale: None,
                 vacation_rules: None,
+                sort_by: None,
             },
         }
     }
@@ -93,6 +96,7 @@ impl Convertable<Config> for ConfigManifest {
                 default_task_duration: None,
                 locale: None,
                 vacation_rules: None,
+                sort_by: None,
             },
         }
     }
@@ -101,6 +105,7 @@ impl Convertable<Config> for ConfigManifest {
         Config {
             manager_name: self.spec.manager_name.clone(),
             manager_email: self.spec.manager_email.clone(),
+            sort_by: self.spec.sort_by.clone(),
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::application::errors::AppError;
-use crate::domain::project_management::{AnyProject, repository::ProjectRepository};
+use crate::domain::project_management::{AnyProject, repository::{ProjectRepository, ProjectRepositoryDiagnostics}};
 use crate::domain::task_management::any_task::AnyTask;
 use crate::domain::shared::code_mapping_service::CodeMappingService;
 use crate::infrastructure::persistence::manifests::{project_manifest::ProjectManifest, task_manifest::TaskManifest};
@@ -335,6 +335,30 @@ impl ProjectRepository for FileProjectRepository {
     }
 }
 
+impl ProjectRepositoryDiagnostics for FileProjectRepository {
+    fn find_invalid_manifests(&self) -> crate::domain::shared::errors::DomainResult<Vec<(String, String)>> {
+        let mut invalid = Vec::new();
+        let projects_dir = self.get_projects_path();
+
+        if !projects_dir.exists() {
+            return Ok(invalid);
+        }
+
+        if let Ok(entries) = std::fs::read_dir(&projects_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("yaml") {
+                    if let Err(e) = self.load_from_path(&path) {
+                        invalid.push((path.to_string_lossy().to_string(), e.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(invalid)
+    }
+}
+
 // ===================================
 // TESTES
 // ===================================
@@ -559,6 +583,41 @@ mod tests {
         assert!(project_file.exists(), "Project file should exist even if corrupted");
     }
 
+    #[test]
+    fn test_find_invalid_manifests_reports_unparseable_project_file() {
+        let temp_dir = tempdir().expect("Failed to create temp directory");
+        let repo_path = temp_dir.path().join("projects");
+        fs::create_dir_all(&repo_path).expect("Failed to create projects directory");
+
+        let repository = FileProjectRepository::with_base_path(repo_path.to_path_buf());
+        let project = create_test_project();
+        repository.save(project.into()).expect("Failed to save project");
+
+        // A second, malformed manifest sitting alongside the valid one.
+        let bad_file = repo_path.join("projects").join("broken.yaml");
+        fs::write(&bad_file, "invalid: yaml: content: [").expect("Failed to write corrupted manifest");
+
+        let invalid = repository
+            .find_invalid_manifests()
+            .expect("find_invalid_manifests should not error");
+
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].0, bad_file.to_string_lossy().to_string());
+    }
+
+    #[test]
+    fn test_find_invalid_manifests_empty_when_projects_dir_missing() {
+        let temp_dir = tempdir().expect("Failed to create temp directory");
+        let repo_path = temp_dir.path().join("projects");
+        let repository = FileProjectRepository::with_base_path(repo_path.to_path_buf());
+
+        let invalid = repository
+            .find_invalid_manifests()
+            .expect("find_invalid_manifests should not error");
+
+        assert!(invalid.is_empty());
+    }
+
     #[test]
     fn test_project_repository_concurrent_access() {
         let temp_dir = tempdir().expect("Failed to create temp directory");
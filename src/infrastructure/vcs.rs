@@ -0,0 +1,158 @@
+//! Version-control port for synchronizing the workspace's YAML store.
+//!
+//! The file-based repositories write directly to the tree under
+//! `companies/`/`projects/` with no way to version or share that state. This
+//! module wraps `git2` behind a small trait so the `sync` use case stays
+//! testable without touching a real repository.
+
+use std::fmt;
+use std::path::Path;
+
+/// Errors surfaced by a [`VcsPort`] implementation.
+#[derive(Debug)]
+pub enum VcsError {
+    /// `workspace_root` is not inside a git repository.
+    NotARepository(String),
+    /// The local and remote histories have diverged and require manual resolution.
+    Conflict(String),
+    /// The named remote could not be reached or does not exist.
+    Remote(String),
+    /// Any other git operation failure.
+    Git(String),
+}
+
+impl fmt::Display for VcsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VcsError::NotARepository(path) => write!(f, "'{}' is not a git repository", path),
+            VcsError::Conflict(msg) => write!(f, "sync conflict: {}", msg),
+            VcsError::Remote(msg) => write!(f, "remote error: {}", msg),
+            VcsError::Git(msg) => write!(f, "git error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for VcsError {}
+
+/// Minimal version-control operations needed to sync the workspace.
+pub trait VcsPort: Send + Sync {
+    /// Stages every change under `workspace_root`, returning the number of entries now staged.
+    fn stage_all(&self, workspace_root: &Path) -> Result<usize, VcsError>;
+
+    /// Commits the currently staged tree, returning the new commit id.
+    fn commit(&self, workspace_root: &Path, message: &str) -> Result<String, VcsError>;
+
+    /// Fetches and fast-forwards `HEAD` from `remote`, failing with [`VcsError::Conflict`]
+    /// when the histories have diverged.
+    fn pull(&self, workspace_root: &Path, remote: &str) -> Result<(), VcsError>;
+
+    /// Pushes the current branch to `remote`.
+    fn push(&self, workspace_root: &Path, remote: &str) -> Result<(), VcsError>;
+}
+
+/// `git2`-backed implementation of [`VcsPort`].
+pub struct Git2VcsAdapter;
+
+impl Git2VcsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn open(workspace_root: &Path) -> Result<git2::Repository, VcsError> {
+        git2::Repository::open(workspace_root)
+            .map_err(|_| VcsError::NotARepository(workspace_root.display().to_string()))
+    }
+}
+
+impl Default for Git2VcsAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VcsPort for Git2VcsAdapter {
+    fn stage_all(&self, workspace_root: &Path) -> Result<usize, VcsError> {
+        let repo = Self::open(workspace_root)?;
+        let mut index = repo.index().map_err(|e| VcsError::Git(e.to_string()))?;
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        index.write().map_err(|e| VcsError::Git(e.to_string()))?;
+
+        // `index.len()` is the index's total entry count, not what this
+        // call actually changed — once anything is tracked it's never 0,
+        // so `SyncWorkspaceUseCase` could never detect "nothing to commit".
+        // Diff the freshly staged index against HEAD's tree (or an empty
+        // tree on a brand-new repo with no HEAD yet) for the real count.
+        let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+        let diff = repo
+            .diff_tree_to_index(head_tree.as_ref(), Some(&index), None)
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        Ok(diff.deltas().len())
+    }
+
+    fn commit(&self, workspace_root: &Path, message: &str) -> Result<String, VcsError> {
+        let repo = Self::open(workspace_root)?;
+        let mut index = repo.index().map_err(|e| VcsError::Git(e.to_string()))?;
+        let tree_id = index.write_tree().map_err(|e| VcsError::Git(e.to_string()))?;
+        let tree = repo.find_tree(tree_id).map_err(|e| VcsError::Git(e.to_string()))?;
+        let signature = repo.signature().map_err(|e| VcsError::Git(e.to_string()))?;
+
+        let parent_commit = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent_commit.iter().collect();
+
+        let commit_id = repo
+            .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+
+        Ok(commit_id.to_string())
+    }
+
+    fn pull(&self, workspace_root: &Path, remote: &str) -> Result<(), VcsError> {
+        let repo = Self::open(workspace_root)?;
+        let mut remote = repo.find_remote(remote).map_err(|e| VcsError::Remote(e.to_string()))?;
+        remote
+            .fetch(&[] as &[&str], None, None)
+            .map_err(|e| VcsError::Remote(e.to_string()))?;
+
+        let fetch_head = repo
+            .find_reference("FETCH_HEAD")
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        let fetch_commit = repo
+            .reference_to_annotated_commit(&fetch_head)
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        let analysis = repo
+            .merge_analysis(&[&fetch_commit])
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+
+        if analysis.0.is_up_to_date() {
+            return Ok(());
+        }
+
+        if !analysis.0.is_fast_forward() {
+            return Err(VcsError::Conflict(
+                "local and remote history have diverged; resolve manually".to_string(),
+            ));
+        }
+
+        let mut head_ref = repo.head().map_err(|e| VcsError::Git(e.to_string()))?;
+        head_ref
+            .set_target(fetch_commit.id(), "fast-forward sync pull")
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        repo.set_head(head_ref.name().ok_or_else(|| VcsError::Git("HEAD has no name".to_string()))?)
+            .map_err(|e| VcsError::Git(e.to_string()))?;
+        repo.checkout_head(Some(git2::build::CheckoutBuilder::default().force()))
+            .map_err(|e| VcsError::Git(e.to_string()))
+    }
+
+    fn push(&self, workspace_root: &Path, remote: &str) -> Result<(), VcsError> {
+        let repo = Self::open(workspace_root)?;
+        let mut remote = repo.find_remote(remote).map_err(|e| VcsError::Remote(e.to_string()))?;
+        let head = repo.head().map_err(|e| VcsError::Git(e.to_string()))?;
+        let refspec = head
+            .name()
+            .ok_or_else(|| VcsError::Git("HEAD has no name".to_string()))?
+            .to_string();
+        remote.push(&[&refspec], None).map_err(|e| VcsError::Remote(e.to_string()))
+    }
+}
@@ -0,0 +1,254 @@
+//! Read-only HTTP admin API exposing the existing list/describe use cases as
+//! JSON, so dashboards and CI scripts can query workspace state without
+//! shelling out to `ttr`.
+//!
+//! Routes are versioned (`/v1alpha1/...`) to mirror the `apiVersion` already
+//! present in the workspace's YAML documents.
+
+use serde::Serialize;
+use std::convert::Infallible;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+use crate::application::{
+    list::{
+        companies::ListCompaniesUseCase, projects::ListProjectsUseCase, resources::ListResourcesUseCase,
+        tasks::ListTasksUseCase,
+    },
+    shared::code_resolver::CodeResolver,
+    task::describe_task::DescribeTaskUseCase,
+};
+use crate::infrastructure::persistence::{
+    company_repository::FileCompanyRepository, project_repository::FileProjectRepository,
+    resource_repository::FileResourceRepository, task_repository::FileTaskRepository,
+};
+use crate::interface::cli::context_manager::ContextManager;
+use crate::interface::cli::logging::Logger;
+
+/// A structured JSON error body returned for any failed request.
+#[derive(Debug, Serialize)]
+struct ApiError {
+    error: String,
+}
+
+/// Starts the read-only admin API on `host:port`, serving until the process
+/// is terminated.
+///
+/// The base path every route resolves repositories against is detected once
+/// at startup via [`ContextManager`] — the same root/company/project
+/// detection `delete_handler`, `list_handler`, and `create_handler` already
+/// use — rather than hardcoding `"."`, so the API behaves the same whether
+/// `ttr api` is invoked from the workspace root or from inside a company or
+/// project directory.
+pub async fn serve(host: String, port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    let addr = if host == "0.0.0.0" {
+        std::net::SocketAddr::from(([0, 0, 0, 0], port))
+    } else if host == "localhost" || host == "127.0.0.1" {
+        std::net::SocketAddr::from(([127, 0, 0, 1], port))
+    } else {
+        let ip: std::net::IpAddr = host.parse()?;
+        std::net::SocketAddr::from((ip, port))
+    };
+
+    let context_manager = ContextManager::new()?;
+    let base_path = context_manager.get_base_path();
+    let routes = routes(base_path).recover(handle_rejection);
+
+    Logger::info(&format!("Admin API listening at http://{}:{}", host, port));
+    Logger::info("Press Ctrl+C to stop the server");
+
+    warp::serve(routes).run(addr).await;
+
+    Ok(())
+}
+
+fn with_base_path(base_path: String) -> impl Filter<Extract = (String,), Error = Infallible> + Clone {
+    warp::any().map(move || base_path.clone())
+}
+
+fn routes(base_path: String) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    let companies = warp::path!("v1alpha1" / "companies")
+        .and(warp::get())
+        .and(with_base_path(base_path.clone()))
+        .and_then(list_companies);
+
+    let projects = warp::path!("v1alpha1" / "projects")
+        .and(warp::get())
+        .and(warp::query::<ProjectsQuery>())
+        .and(with_base_path(base_path.clone()))
+        .and_then(list_projects);
+
+    let resources = warp::path!("v1alpha1" / "resources")
+        .and(warp::get())
+        .and(warp::query::<ResourcesQuery>())
+        .and(with_base_path(base_path.clone()))
+        .and_then(list_resources);
+
+    let tasks = warp::path!("v1alpha1" / "projects" / String / "tasks")
+        .and(warp::get())
+        .and(warp::query::<TasksQuery>())
+        .and(with_base_path(base_path.clone()))
+        .and_then(list_tasks);
+
+    let describe_task = warp::path!("v1alpha1" / "projects" / String / "tasks" / String)
+        .and(warp::get())
+        .and(with_base_path(base_path))
+        .and_then(describe_task);
+
+    companies
+        .or(projects)
+        .or(resources)
+        .or(tasks)
+        .or(describe_task)
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ProjectsQuery {
+    company: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ResourcesQuery {
+    company: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TasksQuery {
+    company: String,
+}
+
+async fn list_companies(base_path: String) -> Result<impl Reply, Rejection> {
+    let repository = FileCompanyRepository::new(&base_path);
+    let use_case = ListCompaniesUseCase::new(repository);
+    match use_case.execute() {
+        Ok(companies) => Ok(warp::reply::json(&companies)),
+        Err(e) => Err(warp::reject::custom(ApiRejection(e.to_string()))),
+    }
+}
+
+async fn list_projects(query: ProjectsQuery, base_path: String) -> Result<impl Reply, Rejection> {
+    let repository = FileProjectRepository::with_base_path(base_path.into());
+    let use_case = ListProjectsUseCase::new(repository);
+    match use_case.execute() {
+        Ok(projects) => {
+            let projects = match &query.company {
+                Some(company) => projects
+                    .into_iter()
+                    .filter(|p| p.company_code() == company)
+                    .collect(),
+                None => projects,
+            };
+            Ok(warp::reply::json(&projects))
+        }
+        Err(e) => Err(warp::reject::custom(ApiRejection(e.to_string()))),
+    }
+}
+
+async fn list_resources(query: ResourcesQuery, base_path: String) -> Result<impl Reply, Rejection> {
+    let repository = FileResourceRepository::new(&base_path);
+    let use_case = ListResourcesUseCase::new(repository);
+    let result = match &query.company {
+        Some(company) => use_case.execute_by_company(company),
+        None => use_case.execute(),
+    };
+    match result {
+        Ok(resources) => Ok(warp::reply::json(&resources)),
+        Err(e) => Err(warp::reject::custom(ApiRejection(e.to_string()))),
+    }
+}
+
+async fn list_tasks(project_code: String, query: TasksQuery, base_path: String) -> Result<impl Reply, Rejection> {
+    let repository = FileTaskRepository::new(&base_path);
+    let use_case = ListTasksUseCase::new(repository);
+    match use_case.execute(&project_code, &query.company) {
+        Ok(tasks) => Ok(warp::reply::json(&tasks)),
+        Err(e) => Err(warp::reject::custom(ApiRejection(e.to_string()))),
+    }
+}
+
+async fn describe_task(project_code: String, task_code: String, base_path: String) -> Result<impl Reply, Rejection> {
+    let project_repository = FileProjectRepository::with_base_path(base_path.clone().into());
+    let code_resolver = CodeResolver::new(&base_path);
+    let use_case = DescribeTaskUseCase::new(project_repository, code_resolver);
+    match use_case.execute(&project_code, &task_code) {
+        Ok(task) => Ok(warp::reply::json(&task)),
+        Err(e) => Err(warp::reject::custom(ApiRejection(e.to_string()))),
+    }
+}
+
+#[derive(Debug)]
+struct ApiRejection(String);
+
+impl warp::reject::Reject for ApiRejection {}
+
+async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
+    let (status, message) = if err.is_not_found() {
+        (StatusCode::NOT_FOUND, "not found".to_string())
+    } else if let Some(ApiRejection(message)) = err.find() {
+        (StatusCode::BAD_REQUEST, message.clone())
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, "internal error".to_string())
+    };
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&ApiError { error: message }),
+        status,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::company_management::company::Company;
+    use tempfile::TempDir;
+    use warp::http::StatusCode;
+
+    fn base_path_with_company(code: &str, name: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = FileCompanyRepository::new(temp_dir.path());
+        let company = Company::new(code.to_string(), name.to_string(), "test@example.com".to_string()).unwrap();
+        repository.save(company).unwrap();
+        temp_dir
+    }
+
+    #[tokio::test]
+    async fn list_companies_returns_companies_under_base_path() {
+        let temp_dir = base_path_with_company("TEST-001", "Test Company");
+        let filter = routes(temp_dir.path().to_string_lossy().to_string());
+
+        let resp = warp::test::request()
+            .method("GET")
+            .path("/v1alpha1/companies")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Vec<serde_json::Value> = serde_json::from_slice(resp.body()).unwrap();
+        assert_eq!(body.len(), 1);
+        assert_eq!(body[0]["code"], "TEST-001");
+    }
+
+    #[tokio::test]
+    async fn describe_task_on_unknown_project_is_rejected_as_bad_request() {
+        let temp_dir = TempDir::new().unwrap();
+        let filter = routes(temp_dir.path().to_string_lossy().to_string()).recover(handle_rejection);
+
+        let resp = warp::test::request()
+            .method("GET")
+            .path("/v1alpha1/projects/NOPE/tasks/NOPE-1")
+            .reply(&filter)
+            .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn unknown_route_is_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        let filter = routes(temp_dir.path().to_string_lossy().to_string()).recover(handle_rejection);
+
+        let resp = warp::test::request().method("GET").path("/v1alpha1/nope").reply(&filter).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+}
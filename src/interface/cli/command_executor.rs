@@ -47,7 +47,7 @@ pub fn execute_init(
     }
 }
 
-pub fn execute_build(output: PathBuf, _base_url: String) -> Result<(), Box<dyn std::error::Error>> {
+pub fn execute_build(output: PathBuf, _base_url: String, watch: bool) -> Result<(), Box<dyn std::error::Error>> {
     use crate::application::build_use_case::BuildUseCase;
 
     if crate::interface::cli::Cli::is_verbose() {
@@ -57,6 +57,10 @@ pub fn execute_build(output: PathBuf, _base_url: String) -> Result<(), Box<dyn s
     let current_dir = std::env::current_dir()?;
     let build_use_case = BuildUseCase::new(current_dir, output.to_str().unwrap_or("dist"))?;
 
+    if watch {
+        return build_use_case.watch();
+    }
+
     match build_use_case.execute() {
         Ok(_) => {
             if !crate::interface::cli::Cli::is_quiet() {
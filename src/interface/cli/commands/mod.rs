@@ -11,6 +11,8 @@ pub mod delete;
 pub mod link;
 pub mod unlink;
 pub mod report;
+pub mod sync;
+pub mod undo;
 pub mod validate;
 pub mod template;
 
@@ -24,5 +26,7 @@ pub use delete::DeleteCommand;
 pub use link::LinkCommand;
 pub use unlink::UnlinkCommand;
 pub use report::ReportCommand;
+pub use sync::SyncCommand;
+pub use undo::UndoCommand;
 pub use validate::ValidateCommand;
 pub use template::TemplateCommand;
@@ -80,6 +80,15 @@ pub enum ProjectCommand {
         #[clap(short, long)]
         company: String,
     },
+    /// Show the project's tasks in dependency order with computed dates
+    Schedule {
+        /// Project code
+        #[clap(long)]
+        code: String,
+        /// Company code
+        #[clap(short, long)]
+        company: String,
+    },
     /// Assign resource to task
     AssignResource {
         /// Project code
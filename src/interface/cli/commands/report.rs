@@ -67,7 +67,7 @@ pub struct ReportArgs {
     pub resource: Option<String>,
 }
 
-pub fn execute_report(args: ReportArgs) -> Result<(), AppError> {
+pub fn execute_report(args: ReportArgs, output: crate::interface::cli::OutputFormat) -> Result<(), AppError> {
     let base_path = Path::new(".");
     let code_resolver = CodeResolver::new(base_path);
     let project_repository = FileProjectRepository::new();
@@ -128,6 +128,14 @@ pub fn execute_report(args: ReportArgs) -> Result<(), AppError> {
 
     if result.success {
         if let Some(data) = result.data {
+            if args.output.is_none() && output != crate::interface::cli::OutputFormat::Table {
+                let rendered = output
+                    .render(&data, String::new)
+                    .map_err(|e| AppError::validation_error("report", e.to_string()))?;
+                println!("{}", rendered);
+                return Ok(());
+            }
+
             println!("✅ Report generated successfully!");
             println!("📊 Title: {}", data.title);
             println!("📅 Generated at: {}", data.generated_at.format("%Y-%m-%d %H:%M:%S"));
@@ -0,0 +1,18 @@
+use clap::Args;
+
+/// Arguments for `ttr sync`: stage, commit, and optionally pull/push the workspace's YAML store.
+#[derive(Args)]
+pub struct SyncCommand {
+    /// Git remote to sync with
+    #[clap(long, default_value = "origin")]
+    pub remote: String,
+    /// Commit message; defaults to an auto-generated summary of what changed
+    #[clap(short, long)]
+    pub message: Option<String>,
+    /// Pull from the remote before committing
+    #[clap(long)]
+    pub pull: bool,
+    /// Skip pushing to the remote after committing
+    #[clap(long)]
+    pub no_push: bool,
+}
@@ -0,0 +1,9 @@
+use clap::Args;
+
+/// Arguments for `ttr undo`: reverse the most recent mutations recorded in the journal.
+#[derive(Args)]
+pub struct UndoCommand {
+    /// Number of journal entries to undo, most recent first
+    #[clap(short = 'n', long, default_value_t = 1)]
+    pub count: usize,
+}
@@ -0,0 +1,192 @@
+//! Shared date-resolution helper for CLI commands that accept a date argument.
+//!
+//! Dates typed by users are rarely strict ISO-8601. [`resolve_date`] first tries
+//! `%Y-%m-%d`, then falls back to a small set of relative/natural forms anchored
+//! to a caller-supplied `today`, so `task create`, `task update`, and future
+//! scheduling commands can all share the same parsing behavior.
+
+use chrono::{Datelike, Months, NaiveDate, Weekday};
+use std::fmt;
+
+/// Error returned when a date string matches none of the accepted forms.
+#[derive(Debug, PartialEq)]
+pub struct DateResolutionError {
+    input: String,
+}
+
+impl fmt::Display for DateResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid date '{}'. Accepted forms: YYYY-MM-DD, today, tomorrow, yesterday, \
+             a weekday name (e.g. friday), 'in N days|weeks|months', 'N days|weeks|months ago', \
+             next week, next month.",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for DateResolutionError {}
+
+/// Resolves `input` to a [`NaiveDate`], anchored to `today`.
+///
+/// Tries the strict `%Y-%m-%d` format first, then falls back to relative and
+/// natural-language forms. Matching is case-insensitive and tolerant of
+/// surrounding whitespace.
+pub fn resolve_date(input: &str, today: NaiveDate) -> Result<NaiveDate, DateResolutionError> {
+    let trimmed = input.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    let lower = trimmed.to_lowercase();
+
+    match lower.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + chrono::Duration::days(1)),
+        "yesterday" => return Ok(today - chrono::Duration::days(1)),
+        "next week" => return Ok(today + chrono::Duration::weeks(1)),
+        "next month" => {
+            return Ok(today
+                .checked_add_months(Months::new(1))
+                .ok_or_else(|| DateResolutionError { input: input.to_string() })?)
+        }
+        _ => {}
+    }
+
+    if let Some(weekday) = parse_weekday(&lower) {
+        return Ok(next_occurrence_of(today, weekday));
+    }
+
+    if let Some(rest) = lower.strip_prefix("in ") {
+        if let Some(date) = parse_quantity_unit(rest, today, Direction::Future) {
+            return Ok(date);
+        }
+    }
+
+    if let Some(rest) = lower.strip_suffix(" ago") {
+        if let Some(date) = parse_quantity_unit(rest, today, Direction::Past) {
+            return Ok(date);
+        }
+    }
+
+    Err(DateResolutionError { input: input.to_string() })
+}
+
+enum Direction {
+    Future,
+    Past,
+}
+
+/// Parses `"<N> days|weeks|months"` and applies it relative to `today`.
+fn parse_quantity_unit(text: &str, today: NaiveDate, direction: Direction) -> Option<NaiveDate> {
+    let mut parts = text.split_whitespace();
+    let amount: i64 = parts.next()?.parse().ok()?;
+    let unit = parts.next()?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let signed_amount = match direction {
+        Direction::Future => amount,
+        Direction::Past => -amount,
+    };
+
+    match unit {
+        "day" | "days" => Some(today + chrono::Duration::days(signed_amount)),
+        "week" | "weeks" => Some(today + chrono::Duration::weeks(signed_amount)),
+        "month" | "months" => {
+            if signed_amount >= 0 {
+                today.checked_add_months(Months::new(signed_amount as u32))
+            } else {
+                today.checked_sub_months(Months::new((-signed_amount) as u32))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_weekday(text: &str) -> Option<Weekday> {
+    match text {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns `today` if it already falls on `weekday`, otherwise the next future date that does.
+fn next_occurrence_of(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let days_ahead = (7 + weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64) % 7;
+    today + chrono::Duration::days(days_ahead)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monday() -> NaiveDate {
+        // 2026-07-27 is a Monday
+        NaiveDate::from_ymd_opt(2026, 7, 27).unwrap()
+    }
+
+    #[test]
+    fn parses_strict_iso_dates() {
+        let today = monday();
+        assert_eq!(resolve_date("2026-08-01", today).unwrap(), NaiveDate::from_ymd_opt(2026, 8, 1).unwrap());
+    }
+
+    #[test]
+    fn parses_today_tomorrow_yesterday() {
+        let today = monday();
+        assert_eq!(resolve_date("today", today).unwrap(), today);
+        assert_eq!(resolve_date("Tomorrow", today).unwrap(), today + chrono::Duration::days(1));
+        assert_eq!(resolve_date("yesterday", today).unwrap(), today - chrono::Duration::days(1));
+    }
+
+    #[test]
+    fn parses_bare_weekday_as_next_future_occurrence() {
+        let today = monday();
+        assert_eq!(resolve_date("friday", today).unwrap(), today + chrono::Duration::days(4));
+        assert_eq!(resolve_date("monday", today).unwrap(), today);
+    }
+
+    #[test]
+    fn parses_in_n_units() {
+        let today = monday();
+        assert_eq!(resolve_date("in 2 days", today).unwrap(), today + chrono::Duration::days(2));
+        assert_eq!(resolve_date("in 3 weeks", today).unwrap(), today + chrono::Duration::weeks(3));
+        assert_eq!(
+            resolve_date("in 1 month", today).unwrap(),
+            today.checked_add_months(Months::new(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_n_units_ago() {
+        let today = monday();
+        assert_eq!(resolve_date("5 days ago", today).unwrap(), today - chrono::Duration::days(5));
+        assert_eq!(resolve_date("2 weeks ago", today).unwrap(), today - chrono::Duration::weeks(2));
+    }
+
+    #[test]
+    fn parses_next_week_and_month() {
+        let today = monday();
+        assert_eq!(resolve_date("next week", today).unwrap(), today + chrono::Duration::weeks(1));
+        assert_eq!(
+            resolve_date("next month", today).unwrap(),
+            today.checked_add_months(Months::new(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_unrecognized_input() {
+        let today = monday();
+        assert!(resolve_date("whenever", today).is_err());
+    }
+}
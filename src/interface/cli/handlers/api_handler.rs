@@ -0,0 +1,6 @@
+use crate::interface::api;
+
+/// Handle the `api` command: start the read-only admin HTTP API.
+pub async fn handle_api_command(port: u16, host: String) -> Result<(), Box<dyn std::error::Error>> {
+    api::serve(host, port).await
+}
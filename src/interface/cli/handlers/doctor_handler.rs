@@ -0,0 +1,85 @@
+use crate::{
+    application::validate::doctor::DoctorUseCase,
+    infrastructure::persistence::{
+        company_repository::FileCompanyRepository, config_repository::FileConfigRepository,
+        project_repository::FileProjectRepository, resource_repository::FileResourceRepository,
+    },
+};
+
+pub fn handle_doctor_command(format: String) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_root = std::env::current_dir()?.to_string_lossy().to_string();
+
+    let project_repository = FileProjectRepository::new();
+    let resource_repository = FileResourceRepository::new(".");
+    let company_repository = FileCompanyRepository::new(".");
+    let config_repository = FileConfigRepository::new();
+
+    let doctor = DoctorUseCase::new(
+        project_repository,
+        resource_repository,
+        company_repository,
+        config_repository,
+        workspace_root,
+    );
+
+    let report = match doctor.execute() {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("❌ Failed to run doctor: {}", e);
+            return Err(e.into());
+        }
+    };
+
+    match format.as_str() {
+        "json" => {
+            let json = serde_json::json!({
+                "workspace_root": report.workspace_root,
+                "manager_name": report.manager_name,
+                "default_timezone": report.default_timezone,
+                "work_hours": report.work_hours,
+                "companies_found": report.companies_found,
+                "projects_found": report.projects_found,
+                "resources_found": report.resources_found,
+                "tasks_found": report.tasks_found,
+                "findings": report.findings,
+            });
+            println!("{}", serde_json::to_string_pretty(&json).unwrap_or_else(|_| "{}".to_string()));
+        }
+        _ => {
+            println!("TTR workspace doctor");
+            println!("=====================");
+            println!("Root: {}", report.workspace_root);
+            println!(
+                "Manager: {}",
+                report.manager_name.as_deref().unwrap_or("<not configured>")
+            );
+            println!(
+                "Timezone: {}",
+                report.default_timezone.as_deref().unwrap_or("<not configured>")
+            );
+            match &report.work_hours {
+                Some((start, end)) => println!("Working hours: {} - {}", start, end),
+                None => println!("Working hours: <not configured>"),
+            }
+            println!();
+            println!("Companies: {}", report.companies_found);
+            println!("Projects:  {}", report.projects_found);
+            println!("Resources: {}", report.resources_found);
+            println!("Tasks:     {}", report.tasks_found);
+            println!();
+            if report.findings.is_empty() {
+                println!("No issues found.");
+            } else {
+                for finding in &report.findings {
+                    println!("{}", finding);
+                }
+            }
+        }
+    }
+
+    if report.has_errors() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
+pub mod api_handler;
 pub mod app_handler;
+pub mod doctor_handler;
 pub mod link_handler;
 pub mod project_handler;
 pub mod report_handler;
 pub mod resource_handler;
+pub mod sync_handler;
 pub mod task_handler;
 pub mod template_handler;
+pub mod undo_handler;
 pub mod unlink_handler;
 
 pub use app_handler::*;
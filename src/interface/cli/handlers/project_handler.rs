@@ -6,6 +6,7 @@ use crate::{
             assign_resource_to_task::AssignResourceToTaskUseCase,
             cancel_project::CancelProjectUseCase,
             describe_project::DescribeProjectUseCase,
+            schedule_project::ScheduleProjectUseCase,
             update_project::{UpdateProjectArgs, UpdateProjectUseCase},
         },
     },
@@ -127,6 +128,31 @@ pub fn handle_project_command(command: ProjectCommand) -> Result<(), Box<dyn std
                 }
             }
         }
+        ProjectCommand::Schedule { code, company: _ } => {
+            let project_repository = FileProjectRepository::with_base_path(".".into());
+            let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+            let schedule_use_case = ScheduleProjectUseCase::new(project_repository, code_resolver);
+
+            match schedule_use_case.execute(&code) {
+                Ok(schedule) => {
+                    println!("✅ Execution plan for project '{}':", code);
+                    for task in &schedule {
+                        println!(
+                            "   {} — start {}, finish {}",
+                            task.code, task.earliest_start, task.earliest_finish
+                        );
+                        if let Some(conflict) = &task.conflict {
+                            println!("     ⚠️  {}", conflict);
+                        }
+                    }
+                    Ok(())
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to schedule project: {}", e);
+                    Err(e.into())
+                }
+            }
+        }
         ProjectCommand::AssignResource {
             project,
             company: _,
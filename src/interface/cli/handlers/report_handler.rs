@@ -1,10 +1,14 @@
 use crate::interface::cli::commands::report::execute_report;
 use crate::interface::cli::commands::ReportCommand;
+use crate::interface::cli::OutputFormat;
 
-pub fn handle_report_command(command: ReportCommand) -> Result<(), Box<dyn std::error::Error>> {
+pub fn handle_report_command(
+    command: ReportCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
     match command {
         ReportCommand::Generate(args) => {
-            execute_report(args)?;
+            execute_report(args, output)?;
         }
     }
     Ok(())
@@ -14,9 +14,13 @@ use crate::{
     },
     infrastructure::persistence::resource_repository::FileResourceRepository,
 };
+use crate::interface::cli::OutputFormat;
 use chrono::NaiveDate;
 
-pub fn handle_resource_command(command: ResourceCommand) -> Result<(), Box<dyn std::error::Error>> {
+pub fn handle_resource_command(
+    command: ResourceCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
     match command {
         ResourceCommand::Create {
             name,
@@ -119,7 +123,8 @@ pub fn handle_resource_command(command: ResourceCommand) -> Result<(), Box<dyn s
 
             match describe_use_case.execute(&code) {
                 Ok(description) => {
-                    println!("{:?}", description);
+                    let rendered = output.render(&description, || format!("{:?}", description))?;
+                    println!("{}", rendered);
                     Ok(())
                 }
                 Err(e) => {
@@ -0,0 +1,38 @@
+use crate::application::sync::sync_workspace::{SyncAppError, SyncArgs, SyncWorkspaceUseCase};
+use crate::infrastructure::vcs::Git2VcsAdapter;
+use crate::interface::cli::commands::SyncCommand;
+
+pub fn handle_sync_command(command: SyncCommand) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_root = std::env::current_dir()?;
+    let use_case = SyncWorkspaceUseCase::new(Git2VcsAdapter::new());
+
+    let args = SyncArgs {
+        remote: command.remote,
+        message: command.message,
+        pull: command.pull,
+        push: !command.no_push,
+    };
+
+    match use_case.execute(&workspace_root, args) {
+        Ok(report) => {
+            println!("✅ Workspace synced!");
+            println!("   Commit: {}", report.commit_id);
+            println!("   Files changed: {}", report.files_changed);
+            if report.pulled {
+                println!("   Pulled from remote");
+            }
+            if report.pushed {
+                println!("   Pushed to remote");
+            }
+            Ok(())
+        }
+        Err(SyncAppError::NothingToCommit) => {
+            println!("Nothing to sync — workspace is already up to date.");
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to sync workspace: {}", e);
+            Err(e.into())
+        }
+    }
+}
@@ -2,6 +2,10 @@ use super::super::commands::TaskCommand;
 use crate::{
     application::{
         create::task::{CreateTaskArgs, CreateTaskUseCase},
+        journal::{
+            entry::{JournalEntry, OperationKind, TaskSnapshot},
+            store::JournalStore,
+        },
         task::{
             assign_resource::AssignResourceToTaskUseCase,
             delete_task::DeleteTaskUseCase,
@@ -10,11 +14,51 @@ use crate::{
             update_task::{UpdateTaskArgs, UpdateTaskUseCase},
         },
     },
-    infrastructure::persistence::{project_repository::FileProjectRepository, task_repository::FileTaskRepository},
+    infrastructure::persistence::{
+        config_repository::FileConfigRepository, project_repository::FileProjectRepository,
+        task_repository::FileTaskRepository,
+    },
 };
-use chrono::NaiveDate;
+use crate::domain::project_management::repository::ProjectRepository;
+use crate::interface::cli::dates::resolve_date;
+use crate::interface::cli::OutputFormat;
+
+/// Appends `operation` to the workspace's journal, logging (but not failing the
+/// command on) any journal I/O error — recording history should never block a
+/// mutation that already succeeded.
+fn record(operation: OperationKind) {
+    if let Ok(workspace_root) = std::env::current_dir() {
+        if let Err(e) = JournalStore::new(&workspace_root).append(JournalEntry::new(operation)) {
+            eprintln!("⚠️  Failed to record journal entry: {}", e);
+        }
+    }
+}
+
+/// Resolves "today" for relative-date parsing (`tomorrow`, `monday`, ...)
+/// in the project's own timezone, falling back to the workspace's configured
+/// default timezone, and finally UTC — never the host's local clock, which
+/// may be in a different zone than the project.
+fn today_in_project_timezone(project_repository: &FileProjectRepository, project_code: &str) -> chrono::NaiveDate {
+    let project_timezone = project_repository
+        .find_by_code(project_code)
+        .ok()
+        .flatten()
+        .and_then(|project| project.timezone().cloned());
 
-pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::error::Error>> {
+    let config_timezone = FileConfigRepository::with_base_path(".".into())
+        .load()
+        .ok()
+        .map(|(config, _)| config.default_timezone);
+
+    let tz: chrono_tz::Tz = project_timezone
+        .or(config_timezone)
+        .and_then(|tz| tz.parse().ok())
+        .unwrap_or(chrono_tz::UTC);
+
+    chrono::Utc::now().with_timezone(&tz).date_naive()
+}
+
+pub fn handle_task_command(command: TaskCommand, output: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
     match command {
         TaskCommand::Create {
             name,
@@ -27,14 +71,14 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             assigned_resources,
         } => {
             let project_repository = FileProjectRepository::with_base_path(".".into());
+            let today = today_in_project_timezone(&project_repository, &project);
+
             let task_repository = FileTaskRepository::new(".");
             let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
             let create_use_case = CreateTaskUseCase::new(project_repository, task_repository, code_resolver);
 
-            let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
-                .map_err(|e| format!("Invalid start date format: {}", e))?;
-            let due = NaiveDate::parse_from_str(&due_date, "%Y-%m-%d")
-                .map_err(|e| format!("Invalid due date format: {}", e))?;
+            let start = resolve_date(&start_date, today)?;
+            let due = resolve_date(&due_date, today)?;
 
             let assigned_resources_vec = assigned_resources
                 .map(|s| s.split(',').map(|s| s.trim().to_string()).collect())
@@ -74,7 +118,8 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
 
             match describe_use_case.execute(&project, &code) {
                 Ok(description) => {
-                    println!("{:?}", description);
+                    let rendered = output.render(&description, || format!("{:?}", description))?;
+                    println!("{}", rendered);
                     Ok(())
                 }
                 Err(e) => {
@@ -95,16 +140,25 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             let project_repository = FileProjectRepository::with_base_path(".".into());
             let task_repository = FileTaskRepository::new(".");
             let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+
+            let previous = DescribeTaskUseCase::new(
+                FileProjectRepository::with_base_path(".".into()),
+                crate::application::shared::code_resolver::CodeResolver::new("."),
+            )
+            .execute(&project, &code)
+            .ok()
+            .map(|task| TaskSnapshot {
+                name: Some(task.name().to_string()),
+                description: task.description().map(|d| d.to_string()),
+                start_date: Some(*task.start_date()),
+                due_date: Some(*task.due_date()),
+            });
+
+            let today = today_in_project_timezone(&project_repository, &project);
             let update_use_case = UpdateTaskUseCase::new(project_repository, task_repository, code_resolver);
 
-            let start = start_date
-                .map(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d"))
-                .transpose()
-                .map_err(|e| format!("Invalid start date format: {}", e))?;
-            let due = due_date
-                .map(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d"))
-                .transpose()
-                .map_err(|e| format!("Invalid due date format: {}", e))?;
+            let start = start_date.map(|d| resolve_date(&d, today)).transpose()?;
+            let due = due_date.map(|d| resolve_date(&d, today)).transpose()?;
 
             let args = UpdateTaskArgs {
                 name,
@@ -116,6 +170,13 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             match update_use_case.execute(&code, &project, args) {
                 Ok(_) => {
                     println!("✅ Task updated successfully!");
+                    if let Some(previous) = previous {
+                        record(OperationKind::UpdateTask {
+                            project: project.clone(),
+                            code: code.clone(),
+                            previous,
+                        });
+                    }
                     Ok(())
                 }
                 Err(e) => {
@@ -136,6 +197,10 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             match delete_use_case.execute(&code, &project) {
                 Ok(_) => {
                     println!("✅ Task cancelled successfully!");
+                    record(OperationKind::DeleteTask {
+                        project: project.clone(),
+                        code: code.clone(),
+                    });
                     Ok(())
                 }
                 Err(e) => {
@@ -157,6 +222,11 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             match link_use_case.execute(&project, &from, &to) {
                 Ok(_) => {
                     println!("✅ Tasks linked successfully!");
+                    record(OperationKind::LinkTask {
+                        project: project.clone(),
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
                     Ok(())
                 }
                 Err(e) => {
@@ -179,6 +249,11 @@ pub fn handle_task_command(command: TaskCommand) -> Result<(), Box<dyn std::erro
             match unlink_use_case.execute(&project, &from, &to) {
                 Ok(_) => {
                     println!("✅ Task link removed successfully!");
+                    record(OperationKind::UnlinkTask {
+                        project: project.clone(),
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
                     Ok(())
                 }
                 Err(e) => {
@@ -0,0 +1,80 @@
+use crate::application::journal::{entry::OperationKind, store::JournalStore};
+use crate::application::resource::activate_resource::ActivateResourceUseCase;
+use crate::application::task::{
+    link_task::LinkTaskUseCase,
+    remove_dependency::RemoveTaskDependencyUseCase,
+    update_task::{UpdateTaskArgs, UpdateTaskUseCase},
+};
+use crate::infrastructure::persistence::{
+    project_repository::FileProjectRepository, resource_repository::FileResourceRepository,
+    task_repository::FileTaskRepository,
+};
+use crate::interface::cli::commands::UndoCommand;
+
+pub fn handle_undo_command(command: UndoCommand) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_root = std::env::current_dir()?;
+    let journal = JournalStore::new(&workspace_root);
+
+    let entries = journal.pop_last(command.count)?;
+    if entries.is_empty() {
+        println!("Nothing to undo — journal is empty.");
+        return Ok(());
+    }
+
+    for entry in entries {
+        match entry.operation {
+            OperationKind::LinkTask { project, from, to } => {
+                let project_repository = FileProjectRepository::with_base_path(".".into());
+                let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+                let unlink_use_case = RemoveTaskDependencyUseCase::new(project_repository, code_resolver);
+                match unlink_use_case.execute(&project, &from, &to) {
+                    Ok(_) => println!("↩️  Reverted: removed link '{}' -> '{}' in project '{}'", from, to, project),
+                    Err(e) => eprintln!("❌ Failed to revert link '{}' -> '{}': {}", from, to, e),
+                }
+            }
+            OperationKind::UnlinkTask { project, from, to } => {
+                let project_repository = FileProjectRepository::with_base_path(".".into());
+                let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+                let link_use_case = LinkTaskUseCase::new(project_repository, code_resolver);
+                match link_use_case.execute(&project, &from, &to) {
+                    Ok(_) => println!("↩️  Reverted: re-linked '{}' -> '{}' in project '{}'", from, to, project),
+                    Err(e) => eprintln!("❌ Failed to revert unlink '{}' -> '{}': {}", from, to, e),
+                }
+            }
+            OperationKind::UpdateTask { project, code, previous } => {
+                let project_repository = FileProjectRepository::with_base_path(".".into());
+                let task_repository = FileTaskRepository::new(".");
+                let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+                let update_use_case = UpdateTaskUseCase::new(project_repository, task_repository, code_resolver);
+
+                let args = UpdateTaskArgs {
+                    name: previous.name,
+                    description: previous.description,
+                    start_date: previous.start_date,
+                    due_date: previous.due_date,
+                };
+                match update_use_case.execute(&code, &project, args) {
+                    Ok(_) => println!("↩️  Reverted: restored previous values for task '{}'", code),
+                    Err(e) => eprintln!("❌ Failed to revert update to task '{}': {}", code, e),
+                }
+            }
+            OperationKind::DeleteTask { project, code } => {
+                println!(
+                    "⚠️  Cannot automatically undo: cancelling task '{}' in project '{}' has no recreate use case yet; revert manually.",
+                    code, project
+                );
+            }
+            OperationKind::DeactivateResource { code, company } => {
+                let resource_repository = FileResourceRepository::new(".");
+                let code_resolver = crate::application::shared::code_resolver::CodeResolver::new(".");
+                let activate_use_case = ActivateResourceUseCase::new(resource_repository, code_resolver);
+                match activate_use_case.execute(&code, &company) {
+                    Ok(_) => println!("↩️  Reverted: reactivated resource '{}'", code),
+                    Err(e) => eprintln!("❌ Failed to revert deactivation of resource '{}': {}", code, e),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
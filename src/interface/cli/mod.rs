@@ -1,16 +1,48 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::{env, path::PathBuf};
 
 pub mod command_executor;
 pub mod commands;
 pub mod completions;
 pub mod context_manager;
+pub mod dates;
 pub mod exit_codes;
 pub mod handlers;
 pub mod logging;
 pub mod simplified_executor;
 pub mod table_formatter;
 
+/// Machine-readable output format shared by `list`, `describe`, and `report` commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable table (default)
+    Table,
+    Json,
+    Yaml,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Table
+    }
+}
+
+impl OutputFormat {
+    /// Serializes `value` according to this format, falling back to the
+    /// provided `table` rendering when the format is `Table`.
+    pub fn render<T: serde::Serialize>(
+        &self,
+        value: &T,
+        table: impl FnOnce() -> String,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            OutputFormat::Table => Ok(table()),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+            OutputFormat::Yaml => Ok(serde_yaml::to_string(value)?),
+        }
+    }
+}
+
 #[derive(Parser)]
 #[clap(author = env!("CARGO_PKG_AUTHORS"),
        version = env!("CARGO_PKG_VERSION"),
@@ -30,6 +62,9 @@ pub struct Cli {
     /// Output logs in JSON format
     #[clap(long, global = true)]
     pub json_logs: bool,
+    /// Output format for list/describe/report commands
+    #[clap(long = "output-format", global = true, value_enum, default_value = "table")]
+    pub output_format: OutputFormat,
     #[clap(subcommand)]
     pub command: Commands,
 }
@@ -118,6 +153,25 @@ pub enum Commands {
         /// Base URL for the site
         #[clap(long, default_value = "https://example.com")]
         base_url: String,
+        /// Watch the source tree and rebuild only the affected pages on change
+        #[clap(short, long)]
+        watch: bool,
+    },
+    /// Inspect the workspace and report its health without mutating anything
+    Doctor {
+        /// Output format (table, json)
+        #[clap(long, default_value = "table")]
+        format: String,
+    },
+    /// Commit and sync the workspace's YAML store over git
+    Sync {
+        #[clap(flatten)]
+        args: commands::SyncCommand,
+    },
+    /// Reverse the most recent mutations recorded in the journal
+    Undo {
+        #[clap(flatten)]
+        args: commands::UndoCommand,
     },
     /// Template management
     #[clap(alias = "tmpl")]
@@ -234,6 +288,18 @@ pub enum Commands {
         #[clap(long)]
         debug: bool,
     },
+    /// Start the read-only admin HTTP API (list/describe as JSON).
+    ///
+    /// Named `api` rather than `serve` to avoid colliding with the existing
+    /// `ttr serve` static-file preview command above.
+    Api {
+        /// Port to listen on
+        #[clap(short, long, default_value = "4000")]
+        port: u16,
+        /// Host to bind to
+        #[clap(long, default_value = "localhost")]
+        host: String,
+    },
     /// Generate shell completions
     Completions {
         /// Shell type (bash, zsh, fish, powershell, elvish)
@@ -289,6 +355,8 @@ impl Cli {
         // Initialize logging
         self.init_logging();
 
+        let output = self.output_format;
+
         match self.command {
             Commands::Init {
                 name,
@@ -309,17 +377,20 @@ impl Cli {
             ),
             Commands::Workspace { command } => handlers::workspace_handler::handle_workspace_command(command),
             Commands::Create { command } => simplified_executor::SimplifiedExecutor::execute_create(command),
-            Commands::List { command } => simplified_executor::SimplifiedExecutor::execute_list(command),
+            Commands::List { command } => simplified_executor::SimplifiedExecutor::execute_list(command, output),
             Commands::Update { command } => simplified_executor::SimplifiedExecutor::execute_update(command),
             Commands::Delete { command } => simplified_executor::SimplifiedExecutor::execute_delete(command),
             Commands::Link { command } => handlers::link_handler::handle_link_command(command),
             Commands::Unlink { command } => handlers::unlink_handler::handle_unlink_command(command),
-            Commands::Report { command } => handlers::report_handler::handle_report_command(command),
+            Commands::Report { command } => handlers::report_handler::handle_report_command(command, output),
             Commands::Validate { command } => command_executor::execute_validate(command),
-            Commands::Build { output, base_url } => command_executor::execute_build(output, base_url),
+            Commands::Build { output, base_url, watch } => command_executor::execute_build(output, base_url, watch),
+            Commands::Doctor { format } => handlers::doctor_handler::handle_doctor_command(format),
+            Commands::Sync { args } => handlers::sync_handler::handle_sync_command(args),
+            Commands::Undo { args } => handlers::undo_handler::handle_undo_command(args),
             Commands::Template { command } => handlers::template_handler::handle_template_command(command),
-            Commands::Task { command } => handlers::task_handler::handle_task_command(command),
-            Commands::Resource { command } => handlers::resource_handler::handle_resource_command(command),
+            Commands::Task { command } => handlers::task_handler::handle_task_command(command, output),
+            Commands::Resource { command } => handlers::resource_handler::handle_resource_command(command, output),
             Commands::Query {
                 query,
                 entity_type,
@@ -406,6 +477,9 @@ impl Cli {
                     cors,
                     debug,
                 )),
+            Commands::Api { port, host } => tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(handlers::api_handler::handle_api_command(port, host)),
             Commands::Completions {
                 shell,
                 install,
@@ -430,3 +504,40 @@ impl Cli {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_flag_defaults_to_table() {
+        let cli = Cli::parse_from(["ttr", "doctor"]);
+        assert_eq!(cli.output_format, OutputFormat::Table);
+    }
+
+    #[test]
+    fn output_flag_parses_before_subcommand() {
+        let cli = Cli::parse_from(["ttr", "--output-format", "json", "doctor"]);
+        assert_eq!(cli.output_format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn output_flag_parses_after_subcommand() {
+        let cli = Cli::parse_from(["ttr", "doctor", "--output-format", "yaml"]);
+        assert_eq!(cli.output_format, OutputFormat::Yaml);
+    }
+
+    #[test]
+    fn output_format_flag_does_not_collide_with_report_output_path() {
+        let cli = Cli::parse_from([
+            "ttr",
+            "--output-format",
+            "json",
+            "report",
+            "generate",
+            "--output",
+            "report.pdf",
+        ]);
+        assert_eq!(cli.output_format, OutputFormat::Json);
+    }
+}
@@ -23,12 +23,26 @@ use crate::application::{
         update_task::{UpdateTaskArgs, UpdateTaskUseCase},
     },
 };
+use crate::application::journal::{
+    entry::{JournalEntry, OperationKind},
+    store::JournalStore,
+};
 use crate::interface::cli::{
     commands::{CreateCommand, DeleteCommand, ListCommand, UpdateCommand},
     context_manager::ContextManager,
     table_formatter::TableFormatter,
-    Cli,
+    Cli, OutputFormat,
 };
+
+/// Appends `operation` to the workspace's journal, logging (but not failing the
+/// command on) any journal I/O error.
+fn record_journal_entry(operation: OperationKind) {
+    if let Ok(workspace_root) = std::env::current_dir() {
+        if let Err(e) = JournalStore::new(&workspace_root).append(JournalEntry::new(operation)) {
+            eprintln!("⚠️  Failed to record journal entry: {}", e);
+        }
+    }
+}
 use chrono::NaiveDate;
 
 /// Simplified command executor that directly calls use cases
@@ -233,7 +247,7 @@ impl SimplifiedExecutor {
     }
 
     /// Execute list commands
-    pub fn execute_list(command: ListCommand) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn execute_list(command: ListCommand, output: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
         let context_manager = ContextManager::new()?;
         
         // Determine context based on command parameters
@@ -283,7 +297,9 @@ impl SimplifiedExecutor {
 
                         match use_case.execute() {
                             Ok(companies) => {
-                                if companies.is_empty() {
+                                if output != OutputFormat::Table {
+                                    println!("{}", output.render(&companies, String::new)?);
+                                } else if companies.is_empty() {
                                     println!("No companies found.");
                                 } else {
                                     let mut table = TableFormatter::new(vec![
@@ -324,7 +340,14 @@ impl SimplifiedExecutor {
 
                 match use_case.execute() {
                     Ok(projects) => {
-                        if company_code == "ALL" {
+                        if output != OutputFormat::Table {
+                            let filtered: Vec<_> = if company_code == "ALL" {
+                                projects
+                            } else {
+                                projects.into_iter().filter(|p| p.company_code() == company_code).collect()
+                            };
+                            println!("{}", output.render(&filtered, String::new)?);
+                        } else if company_code == "ALL" {
                             // Global listing - show all projects
                             if projects.is_empty() {
                                 println!("No projects found.");
@@ -393,7 +416,9 @@ impl SimplifiedExecutor {
 
                         match use_case.execute(&project_code, &company_code) {
                             Ok(tasks) => {
-                                if tasks.is_empty() {
+                                if output != OutputFormat::Table {
+                                    println!("{}", output.render(&tasks, String::new)?);
+                                } else if tasks.is_empty() {
                                     println!("No tasks found for project '{}'.", project_code);
                                 } else {
                                     let mut table = TableFormatter::new(vec![
@@ -431,7 +456,9 @@ impl SimplifiedExecutor {
 
                     match use_case.execute_all_by_company(&company_code) {
                         Ok(tasks) => {
-                            if tasks.is_empty() {
+                            if output != OutputFormat::Table {
+                                println!("{}", output.render(&tasks, String::new)?);
+                            } else if tasks.is_empty() {
                                 println!("No tasks found for company '{}'.", company_code);
                             } else {
                                 let mut table = TableFormatter::new(vec![
@@ -474,7 +501,9 @@ impl SimplifiedExecutor {
 
                 match use_case.execute() {
                     Ok(resources) => {
-                        if resources.is_empty() {
+                        if output != OutputFormat::Table {
+                            println!("{}", output.render(&resources, String::new)?);
+                        } else if resources.is_empty() {
                             println!("No resources found for company '{}'.", company_code);
                         } else {
                             let mut table = TableFormatter::new(vec![
@@ -636,6 +665,9 @@ impl SimplifiedExecutor {
 
                 match use_case.execute(&code) {
                     Ok(_) => {
+                        // Not journaled: `Cancelled` is a terminal `ProjectStatus` (see
+                        // `ProjectStatus::can_transition_to`), so there is no reactivate
+                        // use case to undo this with.
                         println!("✅ Project cancelled successfully!");
                         Ok(())
                     }
@@ -655,6 +687,10 @@ impl SimplifiedExecutor {
                 match use_case.execute(&project_code, &code) {
                     Ok(_) => {
                         println!("✅ Task cancelled successfully!");
+                        record_journal_entry(OperationKind::DeleteTask {
+                            project: project_code.clone(),
+                            code: code.clone(),
+                        });
                         Ok(())
                     }
                     Err(e) => {
@@ -673,6 +709,10 @@ impl SimplifiedExecutor {
                 match use_case.execute(&code, &company_code) {
                     Ok(_) => {
                         println!("✅ Resource deactivated successfully!");
+                        record_journal_entry(OperationKind::DeactivateResource {
+                            code: code.clone(),
+                            company: company_code.clone(),
+                        });
                         Ok(())
                     }
                     Err(e) => {